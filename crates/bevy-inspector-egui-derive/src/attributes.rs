@@ -36,8 +36,31 @@ impl InspectorAttribute {
             InspectorAttribute::Tag(_) => quote! { true },
         }
     }
+
+    /// Whether the attribute applies to the field independent of its type, and is stored in `FieldOptions`
+    pub fn is_field_option(&self) -> bool {
+        matches!(self.lhs(), syn::Member::Named(ident) if FIELD_OPTIONS.iter().any(|option| ident == option))
+    }
+
+    /// Whether the attribute is a field option containing an optional string, like `label = ".."`
+    pub fn is_optional_string_field_option(&self) -> bool {
+        matches!(self.lhs(), syn::Member::Named(ident) if OPTIONAL_STRING_FIELD_OPTIONS.iter().any(|option| ident == option))
+    }
 }
 
+/// Attributes which end up in `FieldOptions` instead of the `DeriveOptions` of the field type
+const FIELD_OPTIONS: &[&str] = &[
+    "readonly",
+    "hidden",
+    "label",
+    "group",
+    "order",
+    "visible_if",
+    "validate",
+];
+/// Field options of type `Option<Cow<'static, str>>`
+const OPTIONAL_STRING_FIELD_OPTIONS: &[&str] = &["label", "group"];
+
 fn parse_inspectable_attributes(
     input: syn::parse::ParseStream,
 ) -> syn::Result<impl Iterator<Item = InspectorAttribute>> {
@@ -57,12 +80,20 @@ fn parse_inspectable_attributes(
         .map(IntoIterator::into_iter)
 }
 
+fn is_inspector_attribute(attr: &syn::Attribute) -> bool {
+    attr.path().get_ident().is_some_and(|p| p == "inspector")
+}
+
+pub fn has_inspector_attribute(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(is_inspector_attribute)
+}
+
 pub fn extract_inspector_attributes(
     attrs: &[syn::Attribute],
 ) -> syn::Result<Vec<InspectorAttribute>> {
     Ok(attrs
         .iter()
-        .filter(|attr| attr.path().get_ident().is_some_and(|p| p == "inspector"))
+        .filter(|attr| is_inspector_attribute(attr))
         .map(|attr| attr.parse_args_with(parse_inspectable_attributes))
         .collect::<syn::Result<Vec<_>>>()?
         .into_iter()
@@ -21,39 +21,24 @@ pub fn inspectable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 fn expand_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream> {
     let bevy_reflect = quote! { ::bevy_inspector_egui::__macro_exports::bevy_reflect };
 
+    let type_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let self_ty = quote! { #type_name #ty_generics };
+
     let fields = data
         .fields
         .iter()
         .filter(|field| !attributes::is_reflect_ignore_field(field))
         .enumerate()
-        .filter_map(|(i, field)| {
-            let ty = &field.ty;
-            let attrs = match attributes::extract_inspector_attributes(&field.attrs) {
-                Ok(attrs) => attrs,
-                Err(e) => return Some(Err(e)),
-            };
-            if attrs.is_empty() {
-                return None;
-            }
-            let attrs = attrs.into_iter().map(|attribute| {
-                let name = attribute.lhs();
-                let value = attribute.rhs();
-                quote! {
-                    field_options.#name = ::std::convert::Into::into(#value);
-                }
-            });
-
-            Some(Ok(quote! {
-                let mut field_options = <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
-                #(#attrs)*
-                options.insert(::bevy_inspector_egui::inspector_options::Target::Field(#i), <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options));
-            }))
+        .map(|(i, field)| {
+            expand_field(
+                field,
+                &self_ty,
+                quote! { ::bevy_inspector_egui::inspector_options::Target::Field(#i) },
+            )
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
-    let type_name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
-
     Ok(quote! {
         impl #impl_generics #bevy_reflect::FromType<#type_name #ty_generics> for ::bevy_inspector_egui::InspectorOptions
         #where_clause
@@ -72,6 +57,10 @@ fn expand_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStr
 fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
     let bevy_reflect = quote! { ::bevy_inspector_egui::__macro_exports::bevy_reflect };
 
+    let type_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let self_ty = quote! { #type_name #ty_generics };
+
     let fields = data
         .variants
         .iter()
@@ -82,42 +71,83 @@ fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream>
                 .iter()
                 .filter(|field| !attributes::is_reflect_ignore_field(field))
                 .enumerate()
-                .filter_map(|(field_index, field)| {
-                    let ty = &field.ty;
-                    let attrs = match attributes::extract_inspector_attributes(&field.attrs) {
-                        Ok(attrs) => attrs,
-                        Err(e) => return Some(Err(e)),
-                    };
-                    if attrs.is_empty() {
-                        return None;
-                    }
-                    let attrs = attrs.into_iter().map(|attribute| {
-                        let name = attribute.lhs();
-                        let value = attribute.rhs();
+                .map(|(field_index, field)| {
+                    expand_field(
+                        field,
+                        &self_ty,
                         quote! {
-                            field_options.#name = std::convert::Into::into(#value);
-                        }
-                    });
-
-                    Some(Ok(quote! {
-                        let mut field_options = <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
-                        #(#attrs)*
-                        options.insert(
                             ::bevy_inspector_egui::inspector_options::Target::VariantField {
                                 variant_index: #variant_index,
                                 field_index: #field_index,
-                            },
-                            <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options)
-                        );
-                    }))
+                            }
+                        },
+                    )
                 })
                 .collect::<syn::Result<Vec<_>>>()?;
             Ok(attrs)
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
-    let type_name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let enum_options = attributes::extract_inspector_attributes(&input.attrs)?
+        .into_iter()
+        .map(|attribute| {
+            let name = attribute.lhs();
+            let value = attribute.rhs();
+            quote! {
+                options.enum_options_mut().#name = ::std::convert::Into::into(#value);
+            }
+        });
+    let variant_options = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let variant_name = variant.ident.to_string();
+            attributes::extract_inspector_attributes(&variant.attrs)?
+                .into_iter()
+                .map(|attribute| match attribute {
+                    attributes::InspectorAttribute::Tag(syn::Member::Named(ident))
+                        if ident == "hidden" =>
+                    {
+                        Ok(quote! {
+                            options.enum_options_mut().hidden_variants.push(#variant_name);
+                        })
+                    }
+                    attributes::InspectorAttribute::Assignment(
+                        syn::Member::Named(ident),
+                        value,
+                    ) if ident == "label" => Ok(quote! {
+                        options.enum_options_mut().variant_labels.push((#variant_name, #value));
+                    }),
+                    attribute => Err(syn::Error::new_spanned(
+                        attribute.lhs(),
+                        "expected `hidden` or `label = \"..\"` on an enum variant",
+                    )),
+                })
+                .collect::<syn::Result<Vec<_>>>()
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // fields of the enum type can only set `EnumOptions` if the enum opts in with an `#[inspector(..)]` attribute,
+    // so that enums without one can still implement `InspectorOptionsType` themselves
+    let opts_in = attributes::has_inspector_attribute(&input.attrs)
+        || data
+            .variants
+            .iter()
+            .any(|variant| attributes::has_inspector_attribute(&variant.attrs));
+    let options_type_impl = opts_in.then(|| {
+        quote! {
+            impl #impl_generics ::bevy_inspector_egui::inspector_options::InspectorOptionsType for #type_name #ty_generics
+            #where_clause
+            {
+                type DeriveOptions = ::bevy_inspector_egui::inspector_options::EnumOptions;
+                type Options = ::bevy_inspector_egui::inspector_options::EnumOptions;
+
+                fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+                    options
+                }
+            }
+        }
+    });
 
     Ok(quote! {
         impl #impl_generics #bevy_reflect::FromType<#type_name #ty_generics> for ::bevy_inspector_egui::InspectorOptions
@@ -126,13 +156,110 @@ fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream>
             fn from_type() -> Self {
                 let mut options = ::bevy_inspector_egui::InspectorOptions::default();
 
+                #(#enum_options)*
+                #(#(#variant_options)*)*
                 #(#(#fields)*)*
 
                 options
             }
         }
+
+        #options_type_impl
     })
 }
+/// `self_ty` is the type containing the field, used for `visible_if = fn(&Self) -> bool`
+fn expand_field(
+    field: &syn::Field,
+    self_ty: &TokenStream,
+    target: TokenStream,
+) -> syn::Result<TokenStream> {
+    let ty = &field.ty;
+    let (field_attrs, type_attrs): (Vec<_>, Vec<_>) =
+        attributes::extract_inspector_attributes(&field.attrs)?
+            .into_iter()
+            .partition(attributes::InspectorAttribute::is_field_option);
+
+    let field_options = field_attrs
+        .iter()
+        .map(|attribute| {
+            let name = attribute.lhs();
+            let value = attribute.rhs();
+            match attribute {
+                attributes::InspectorAttribute::Assignment(..)
+                    if attribute.is_optional_string_field_option() =>
+                {
+                    Ok(quote! {
+                        field_options.#name = ::std::option::Option::Some(::std::convert::Into::into(#value));
+                    })
+                }
+                attributes::InspectorAttribute::Tag(member)
+                    if attribute.is_optional_string_field_option() =>
+                {
+                    Err(syn::Error::new_spanned(
+                        member,
+                        format!("expected a value: `{} = \"..\"`", quote!(#member)),
+                    ))
+                }
+                attributes::InspectorAttribute::Assignment(syn::Member::Named(ident), _)
+                    if ident == "visible_if" =>
+                {
+                    Ok(quote! {
+                        field_options.#name = ::std::option::Option::Some(|value: &dyn ::std::any::Any| {
+                            match value.downcast_ref::<#self_ty>() {
+                                ::std::option::Option::Some(value) => (#value)(value),
+                                ::std::option::Option::None => true,
+                            }
+                        });
+                    })
+                }
+                attributes::InspectorAttribute::Assignment(syn::Member::Named(ident), _)
+                    if ident == "validate" =>
+                {
+                    Ok(quote! {
+                        field_options.#name = ::std::option::Option::Some(|value: &dyn ::std::any::Any| {
+                            match value.downcast_ref::<#ty>() {
+                                ::std::option::Option::Some(value) => (#value)(value),
+                                ::std::option::Option::None => ::std::result::Result::Ok(()),
+                            }
+                        });
+                    })
+                }
+                _ => Ok(quote! {
+                    field_options.#name = #value;
+                }),
+            }
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let field_options = (!field_options.is_empty()).then(|| {
+        quote! {
+            {
+                let field_options = options.field_options_mut(#target);
+                #(#field_options)*
+            }
+        }
+    });
+
+    let type_options = (!type_attrs.is_empty()).then(|| {
+        let attrs = type_attrs.into_iter().map(|attribute| {
+            let name = attribute.lhs();
+            let value = attribute.rhs();
+            quote! {
+                field_options.#name = ::std::convert::Into::into(#value);
+            }
+        });
+        quote! {
+            let mut field_options = <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
+            #(#attrs)*
+            options.insert(#target, <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options));
+        }
+    });
+
+    Ok(quote! {
+        #field_options
+        #type_options
+    })
+}
+
 fn expand_union(_: &DeriveInput, data: &DataUnion) -> syn::Result<TokenStream> {
     Err(syn::Error::new_spanned(
         data.union_token,
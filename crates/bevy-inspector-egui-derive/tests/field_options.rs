@@ -0,0 +1,161 @@
+use bevy_inspector_egui::{
+    inspector_options::{std_options::NumberOptions, Target},
+    InspectorOptions,
+};
+use bevy_reflect::{FromType, Reflect};
+
+#[test]
+fn field_options_struct() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(readonly)]
+        cached: String,
+        #[inspector(hidden)]
+        bookkeeping: Vec<u32>,
+        #[inspector(label = "Max speed", min = 0.0)]
+        max_speed: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    assert_eq!(options.iter().count(), 1);
+
+    let cached = options.field_options(Target::Field(0)).unwrap();
+    assert!(cached.readonly);
+    assert!(!cached.hidden);
+
+    let bookkeeping = options.field_options(Target::Field(1)).unwrap();
+    assert!(bookkeeping.hidden);
+
+    let max_speed = options.field_options(Target::Field(2)).unwrap();
+    assert_eq!(max_speed.label.as_deref(), Some("Max speed"));
+    let max_speed_options = options
+        .get(Target::Field(2))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(max_speed_options.min, Some(0.0));
+}
+
+#[test]
+fn field_options_enum() {
+    #[derive(Reflect, InspectorOptions)]
+    enum Test {
+        A(#[inspector(readonly)] u8),
+        B {
+            #[reflect(ignore)]
+            _ignored: f32,
+            #[inspector(label = "Name")]
+            name: String,
+        },
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    assert_eq!(options.iter().count(), 0);
+
+    let a = options
+        .field_options(Target::VariantField {
+            variant_index: 0,
+            field_index: 0,
+        })
+        .unwrap();
+    assert!(a.readonly);
+
+    let name = options
+        .field_options(Target::VariantField {
+            variant_index: 1,
+            field_index: 0,
+        })
+        .unwrap();
+    assert_eq!(name.label.as_deref(), Some("Name"));
+}
+
+#[test]
+fn field_options_group_order() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        name: String,
+        #[inspector(group = "Physics", order = 2)]
+        mass: f32,
+        #[inspector(group = "Physics", order = -1, min = 0.0)]
+        drag: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    assert!(options.field_options(Target::Field(0)).is_none());
+
+    let mass = options.field_options(Target::Field(1)).unwrap();
+    assert_eq!(mass.group.as_deref(), Some("Physics"));
+    assert_eq!(mass.order, 2);
+
+    let drag = options.field_options(Target::Field(2)).unwrap();
+    assert_eq!(drag.group.as_deref(), Some("Physics"));
+    assert_eq!(drag.order, -1);
+    assert!(options.get(Target::Field(2)).is_some());
+}
+
+#[test]
+fn field_options_visible_if() {
+    #[derive(Reflect, PartialEq)]
+    enum Mode {
+        Constant,
+        Falloff,
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Light {
+        mode: Mode,
+        #[inspector(visible_if = Light::has_falloff)]
+        falloff_radius: f32,
+    }
+
+    impl Light {
+        fn has_falloff(&self) -> bool {
+            self.mode == Mode::Falloff
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Light>>::from_type();
+    let visible_if = options
+        .field_options(Target::Field(1))
+        .unwrap()
+        .visible_if
+        .unwrap();
+
+    let light = Light {
+        mode: Mode::Constant,
+        falloff_radius: 1.0,
+    };
+    assert!(!visible_if(&light));
+    let light = Light {
+        mode: Mode::Falloff,
+        ..light
+    };
+    assert!(visible_if(&light));
+}
+
+#[test]
+fn field_options_validate() {
+    fn positive(value: &f32) -> Result<(), String> {
+        if *value <= 0.0 {
+            return Err("must be positive".into());
+        }
+        Ok(())
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(validate = positive)]
+        mass: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let validate = options
+        .field_options(Target::Field(0))
+        .unwrap()
+        .validate
+        .unwrap();
+
+    assert!(validate(&-1.0f32).is_err());
+    assert!(validate(&1.0f32).is_ok());
+    assert!(validate(&String::new()).is_ok());
+}
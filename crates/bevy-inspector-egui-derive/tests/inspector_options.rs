@@ -1,13 +1,17 @@
 use bevy_ecs::entity::Entity;
 use bevy_inspector_egui::{
     inspector_options::{
-        std_options::{EntityDisplay, EntityOptions, NumberOptions, QuatDisplay, QuatOptions},
-        Target,
+        std_options::{
+            Angle, EntityDisplay, EntityOptions, ListOptions, MatrixDisplay, MatrixOptions,
+            NumberOptions, QuatDisplay, QuatOptions, StringOptions, Unit,
+        },
+        EnumDisplay, EnumOptions, InspectorOptionsType, Target,
     },
     InspectorOptions,
 };
-use bevy_math::Quat;
+use bevy_math::{EulerRot, Mat4, Quat};
 use bevy_reflect::{FromType, Reflect};
+use std::num::{NonZeroU32, Wrapping};
 
 #[test]
 fn expr_attribute() {
@@ -50,7 +54,344 @@ fn expr_attribute() {
     let entity_options = options
         .get(Target::Field(2))
         .unwrap()
+        .downcast_ref::<ListOptions>()
+        .unwrap()
+        .element
         .downcast_ref::<EntityOptions>()
         .unwrap();
     assert!(matches!(entity_options.display, EntityDisplay::Id));
 }
+
+#[test]
+fn quat_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(euler_rot = EulerRot::ZXY, unit = Angle::Degrees)]
+        rotation: Quat,
+        #[inspector(display = QuatDisplay::Arcball)]
+        arcball: Quat,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let rotation_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<QuatOptions>()
+        .unwrap();
+    assert!(matches!(rotation_options.display, QuatDisplay::Euler));
+    assert_eq!(rotation_options.euler_rot, EulerRot::ZXY);
+    assert_eq!(rotation_options.unit, Some(Angle::Degrees));
+
+    let arcball_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<QuatOptions>()
+        .unwrap();
+    assert!(matches!(arcball_options.display, QuatDisplay::Arcball));
+    assert_eq!(arcball_options.euler_rot, EulerRot::XYZ);
+}
+
+#[test]
+fn matrix_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(display = MatrixDisplay::Decomposed)]
+        decomposed: Mat4,
+        raw: Mat4,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let decomposed_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<MatrixOptions>()
+        .unwrap();
+    assert_eq!(decomposed_options.display, Some(MatrixDisplay::Decomposed));
+    assert!(options.get(Target::Field(1)).is_none());
+}
+
+#[test]
+fn number_scale_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(
+            min = 0.001,
+            max = 1000.0,
+            soft_max = 10.0,
+            logarithmic,
+            step = 0.5,
+            decimals = 2
+        )]
+        value: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let number_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(number_options.min, Some(0.001));
+    assert_eq!(number_options.soft_max, Some(10.0));
+    assert!(number_options.logarithmic);
+    assert_eq!(number_options.step, Some(0.5));
+    assert_eq!(number_options.decimals, Some(2));
+}
+
+#[test]
+fn unit_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(unit = Angle::Degrees)]
+        angle: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let number_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    let unit = number_options.unit.unwrap();
+    assert_eq!(unit, Unit::Angle(Angle::Degrees));
+    assert!((unit.from_base(std::f64::consts::PI) - 180.0).abs() < 1e-9);
+    assert!((unit.to_base(90.0) - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+}
+
+#[test]
+fn nonzero_and_wrapping_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(max = 16)]
+        nonzero: NonZeroU32,
+        #[inspector(min = -8)]
+        wrapping: Wrapping<i8>,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let nonzero_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<u32>>()
+        .unwrap();
+    assert_eq!(nonzero_options.max, Some(16));
+
+    let wrapping_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<NumberOptions<i8>>()
+        .unwrap();
+    assert_eq!(wrapping_options.min, Some(-8));
+}
+
+#[test]
+fn list_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(min_len = 1, max_len = 3, allow_reorder = false, min = 0.0)]
+        list: Vec<f32>,
+        #[inspector(fixed_len = 2)]
+        fixed: Vec<f32>,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let list_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<ListOptions>()
+        .unwrap();
+    assert!(!list_options.allow_reorder);
+    assert!(!list_options.can_remove(1));
+    assert!(list_options.can_remove(2));
+    assert!(!list_options.can_add(3));
+    let element_options = list_options
+        .element
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(element_options.min, Some(0.0));
+
+    let fixed_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<ListOptions>()
+        .unwrap();
+    assert!(!fixed_options.can_add(0));
+    assert!(!fixed_options.can_remove(2));
+}
+
+#[test]
+fn enum_options() {
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(display = EnumDisplay::Segmented)]
+    enum Quality {
+        #[inspector(label = "Potato")]
+        Low,
+        High,
+        #[inspector(hidden)]
+        Debug,
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Settings {
+        #[inspector(display = EnumDisplay::Radio, hidden_variants = ["High"])]
+        quality: Quality,
+    }
+
+    let quality_options = <InspectorOptions as FromType<Quality>>::from_type();
+    let enum_options = quality_options.enum_options().unwrap();
+    assert_eq!(enum_options.display, Some(EnumDisplay::Segmented));
+    assert_eq!(enum_options.label("Low"), "Potato");
+    assert_eq!(enum_options.label("High"), "High");
+    assert!(enum_options.is_hidden("Debug"));
+
+    let settings_options = <InspectorOptions as FromType<Settings>>::from_type();
+    let field_options = settings_options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<EnumOptions>()
+        .unwrap();
+    let merged = field_options.merged_with(Some(enum_options));
+    assert_eq!(merged.display, Some(EnumDisplay::Radio));
+    assert!(merged.is_hidden("High"));
+    assert!(merged.is_hidden("Debug"));
+    assert_eq!(merged.label("Low"), "Potato");
+}
+
+#[test]
+fn visible_if_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Scale(bool, #[inspector(visible_if = Scale::is_uniform)] f32);
+
+    impl Scale {
+        fn is_uniform(&self) -> bool {
+            self.0
+        }
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    enum Light {
+        Spot {
+            shadows: bool,
+            #[inspector(visible_if = Light::has_shadows)]
+            shadow_bias: f32,
+        },
+    }
+
+    impl Light {
+        fn has_shadows(&self) -> bool {
+            matches!(self, Light::Spot { shadows: true, .. })
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Scale>>::from_type();
+    let visible_if = options
+        .field_options(Target::Field(1))
+        .unwrap()
+        .visible_if
+        .unwrap();
+    assert!(visible_if(&Scale(true, 1.0)));
+    assert!(!visible_if(&Scale(false, 1.0)));
+
+    let options = <InspectorOptions as FromType<Light>>::from_type();
+    let target = Target::VariantField {
+        variant_index: 0,
+        field_index: 1,
+    };
+    let visible_if = options.field_options(target).unwrap().visible_if.unwrap();
+    let light = |shadows| Light::Spot {
+        shadows,
+        shadow_bias: 0.0,
+    };
+    assert!(visible_if(&light(true)));
+    assert!(!visible_if(&light(false)));
+}
+
+#[test]
+fn string_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Login {
+        #[inspector(max_length = 16, hint_text = "user name", suggestions = ["admin", "guest"])]
+        name: String,
+        #[inspector(password, suggestions = ["hunter2"])]
+        password: String,
+        #[inspector(multiline, code)]
+        script: String,
+    }
+
+    let options = <InspectorOptions as FromType<Login>>::from_type();
+    let string_options = |field| {
+        options
+            .get(Target::Field(field))
+            .unwrap()
+            .downcast_ref::<StringOptions>()
+            .unwrap()
+    };
+
+    let name = string_options(0);
+    assert_eq!(name.max_length, Some(16));
+    assert_eq!(name.hint_text, "user name");
+    assert_eq!(name.suggestions.get(), ["admin", "guest"]);
+    assert!(!name.password);
+
+    let password = string_options(1);
+    assert!(password.password);
+    assert_eq!(password.suggestions.get(), ["hunter2"]);
+
+    let script = string_options(2);
+    assert!(script.multiline);
+    assert!(script.code);
+    assert!(script.suggestions.is_none());
+}
+
+#[test]
+fn enum_without_attributes_keeps_own_options_type() {
+    #[derive(Reflect, InspectorOptions)]
+    enum Mode {
+        A,
+        B,
+    }
+
+    impl InspectorOptionsType for Mode {
+        type DeriveOptions = NumberOptions<f32>;
+        type Options = NumberOptions<f32>;
+
+        fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+            options
+        }
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector()]
+    enum OptedIn {
+        A,
+        B,
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Settings {
+        #[inspector(min = 1.0)]
+        mode: Mode,
+        #[inspector(display = EnumDisplay::Radio)]
+        opted_in: OptedIn,
+    }
+
+    let options = <InspectorOptions as FromType<Settings>>::from_type();
+    let mode_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(mode_options.min, Some(1.0));
+    let opted_in_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<EnumOptions>()
+        .unwrap();
+    assert_eq!(opted_in_options.display, Some(EnumDisplay::Radio));
+}
@@ -13,7 +13,7 @@ struct Config {
     font_size: f32,
     #[inspector(min = -1.0, speed = 0.001)] // you can specify inner options for `Option<T>`
     option: Option<f32>,
-    #[inspector(min = 10, max = 20)] // same for Vec<T>
+    #[inspector(min = 10, max = 20, max_len = 5)] // same for Vec<T>, next to its `ListOptions`
     vec: Vec<u32>,
     hash_map: HashMap<u32, String>,
 }
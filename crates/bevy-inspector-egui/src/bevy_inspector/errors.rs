@@ -125,6 +125,16 @@ pub fn dead_asset_handle(ui: &mut egui::Ui, handle: UntypedAssetId) {
     ui.label(job);
 }
 
+pub fn asset_load_failed(ui: &mut egui::Ui, path: &str, reason: &str) {
+    let job = layout_job(&[
+        (FontId::proportional(13.0), "Could not load "),
+        (FontId::monospace(12.0), path),
+        (FontId::proportional(13.0), &format!(": {reason}")),
+    ]);
+
+    ui.label(job);
+}
+
 pub fn state_does_not_exist(ui: &mut egui::Ui, name: &str) {
     let job = layout_job(&[
         (FontId::proportional(13.0), "State "),
@@ -0,0 +1,921 @@
+//! Undo/redo history for edits made through the inspector
+//!
+//! Edits to entity components, to resources displayed with [`ui_for_resource`](super::ui_for_resource),
+//! to assets displayed with [`ui_for_assets`](super::ui_for_assets) and to states displayed with [`ui_for_state`](super::ui_for_state)
+//! are recorded in the [`InspectorHistory`] resource, which is initialized by the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin).
+//!
+//! Each [`HistoryEntry`] stores the edited value, the [reflect path](bevy_reflect::ParsedPath) of the field that changed,
+//! and the value before and after the edit. Use [`undo`] and [`redo`] to walk through the history, or call
+//! [`handle_shortcuts`] once per frame to bind them to `Ctrl+Z` and `Ctrl+Shift+Z`.
+//!
+//! Every edit, including undo and redo, is also sent as an [`InspectorEdit`] event and triggered for observers:
+//! ```rust
+//! # use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::history::{EditTarget, InspectorEdit};
+//!
+//! fn react_to_edits(mut edits: EventReader<InspectorEdit>) {
+//!     for edit in edits.read() {
+//!         if let EditTarget::Component { entity, .. } = edit.target {
+//!             println!("{entity} was edited at {}", edit.path);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ```rust
+//! # use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::{self, history};
+//!
+//! fn inspector_ui(world: &mut World, ctx: &egui::Context, ui: &mut egui::Ui) {
+//!     history::handle_shortcuts(world, ctx);
+//!     bevy_inspector::ui_for_world(world, ui);
+//! }
+//! ```
+
+use std::{
+    any::TypeId,
+    sync::{Arc, Mutex},
+};
+
+use bevy_asset::{ReflectAsset, UntypedAssetId, UntypedHandle};
+use bevy_ecs::{event::Events, prelude::*, world::CommandQueue};
+use bevy_reflect::{Access, ParsedPath, PartialReflect, ReflectPath, ReflectRef};
+use bevy_state::reflect::{ReflectFreelyMutableState, ReflectState};
+
+/// Edits of the same field following each other within this many seconds are merged into one entry.
+const MERGE_WINDOW: f64 = 0.5;
+
+/// The value an inspector edit was made to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EditTarget {
+    /// A component of an entity
+    Component { entity: Entity, component: TypeId },
+    /// A resource
+    Resource(TypeId),
+    /// An asset in its [`Assets`](bevy_asset::Assets) collection
+    Asset {
+        asset_type: TypeId,
+        id: UntypedAssetId,
+    },
+    /// A [`State`](bevy_state::state::State), edits are applied as the [`NextState`](bevy_state::state::NextState)
+    State(TypeId),
+}
+
+/// Event sent and triggered for every edit made through the inspector, including [`undo`] and [`redo`].
+///
+/// Requires the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin) to be read with an [`EventReader`],
+/// observers are triggered either way.
+///
+/// The event is sent even if the edit isn't recorded in the [`InspectorHistory`], e.g. because the changed field
+/// couldn't be determined. In that case `path` is empty and `old` is `None`.
+#[derive(Event, Debug)]
+pub struct InspectorEdit {
+    /// The edited value
+    pub target: EditTarget,
+    /// Path to the field that was changed, relative to the target
+    pub path: ParsedPath,
+    /// The value at `path` before the edit, if it is known and can be cloned
+    pub old: Option<Box<dyn PartialReflect>>,
+    /// The value at `path` after the edit, if it can be cloned
+    pub new: Option<Box<dyn PartialReflect>>,
+}
+
+impl InspectorEdit {
+    fn from_entry(entry: &HistoryEntry, undo: bool) -> Self {
+        let (old, new) = if undo {
+            (&entry.new, &entry.old)
+        } else {
+            (&entry.old, &entry.new)
+        };
+        InspectorEdit {
+            target: entry.target,
+            path: entry.path.clone(),
+            old: clone_value(&**old),
+            new: clone_value(&**new),
+        }
+    }
+
+    fn send(mut self, world: &mut World) {
+        world.trigger_ref(&mut self);
+        if world.contains_resource::<Events<InspectorEdit>>() {
+            world.send_event(self);
+        }
+    }
+}
+
+/// A single recorded edit
+#[derive(Debug)]
+pub struct HistoryEntry {
+    /// The edited value
+    pub target: EditTarget,
+    /// Path to the field that was changed, relative to the target
+    pub path: ParsedPath,
+    /// The value at `path` before the edit
+    pub old: Box<dyn PartialReflect>,
+    /// The value at `path` after the edit
+    pub new: Box<dyn PartialReflect>,
+    time: f64,
+}
+
+/// Resource storing the undo and redo stacks of edits made in the inspector.
+#[derive(Resource, Debug)]
+pub struct InspectorHistory {
+    undo: Vec<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+    /// The maximum number of entries kept on the undo stack
+    pub max_len: usize,
+}
+
+impl Default for InspectorHistory {
+    fn default() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            max_len: 100,
+        }
+    }
+}
+
+impl InspectorHistory {
+    /// Push a new entry onto the undo stack, clearing the redo stack.
+    ///
+    /// If the last entry edited the same field shortly before, the two are merged so that e.g. dragging a value
+    /// only results in one entry.
+    pub fn record(&mut self, entry: HistoryEntry) {
+        self.redo.clear();
+
+        if let Some(last) = self.undo.last_mut()
+            && last.target == entry.target
+            && last.path == entry.path
+            && entry.time - last.time < MERGE_WINDOW
+        {
+            last.new = entry.new;
+            last.time = entry.time;
+            return;
+        }
+
+        self.undo.push(entry);
+        if self.undo.len() > self.max_len {
+            let excess = self.undo.len() - self.max_len;
+            self.undo.drain(..excess);
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// The entries that can be undone, oldest first
+    pub fn undo_stack(&self) -> &[HistoryEntry] {
+        &self.undo
+    }
+    /// The entries that can be redone, most recently undone last
+    pub fn redo_stack(&self) -> &[HistoryEntry] {
+        &self.redo
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+/// Revert the most recent edit. Returns `false` if there was nothing to undo or the target no longer exists.
+pub fn undo(world: &mut World) -> bool {
+    let Some(entry) = world
+        .get_resource_mut::<InspectorHistory>()
+        .and_then(|mut history| history.undo.pop())
+    else {
+        return false;
+    };
+
+    let applied = apply_to_target(world, entry.target, &entry.path, &*entry.old);
+    if applied {
+        let edit = InspectorEdit::from_entry(&entry, true);
+        let mut history = world.resource_mut::<InspectorHistory>();
+        history.redo.push(entry);
+        edit.send(world);
+    }
+    applied
+}
+
+/// Reapply the most recently undone edit. Returns `false` if there was nothing to redo or the target no longer exists.
+pub fn redo(world: &mut World) -> bool {
+    let Some(entry) = world
+        .get_resource_mut::<InspectorHistory>()
+        .and_then(|mut history| history.redo.pop())
+    else {
+        return false;
+    };
+
+    let applied = apply_to_target(world, entry.target, &entry.path, &*entry.new);
+    if applied {
+        let edit = InspectorEdit::from_entry(&entry, false);
+        let mut history = world.resource_mut::<InspectorHistory>();
+        history.undo.push(entry);
+        edit.send(world);
+    }
+    applied
+}
+
+/// Call [`undo`] on `Ctrl+Z` and [`redo`] on `Ctrl+Shift+Z` (`Cmd` on mac).
+///
+/// Does nothing while a text field has keyboard focus, so that its own undo keeps working.
+pub fn handle_shortcuts(world: &mut World, ctx: &egui::Context) {
+    if ctx.wants_keyboard_input() {
+        return;
+    }
+
+    let (undo_pressed, redo_pressed) = ctx.input_mut(|input| {
+        // redo first, `Ctrl+Z` would also match `Ctrl+Shift+Z`
+        let redo = input.consume_shortcut(&egui::KeyboardShortcut::new(
+            egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+            egui::Key::Z,
+        ));
+        let undo = input.consume_shortcut(&egui::KeyboardShortcut::new(
+            egui::Modifiers::COMMAND,
+            egui::Key::Z,
+        ));
+        (undo, redo)
+    });
+
+    if redo_pressed {
+        redo(world);
+    } else if undo_pressed {
+        undo(world);
+    }
+}
+
+fn apply_to_target(
+    world: &mut World,
+    target: EditTarget,
+    path: &ParsedPath,
+    value: &dyn PartialReflect,
+) -> bool {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    match target {
+        EditTarget::Component { entity, component } => {
+            let Some(reflect_component) =
+                type_registry.get_type_data::<ReflectComponent>(component)
+            else {
+                return false;
+            };
+            let Ok(mut entity) = world.get_entity_mut(entity) else {
+                return false;
+            };
+            let Some(mut root) = reflect_component.reflect_mut(&mut entity) else {
+                return false;
+            };
+            apply_at_path(root.as_partial_reflect_mut(), path, value)
+        }
+        EditTarget::Resource(resource) => {
+            let Some(reflect_resource) = type_registry.get_type_data::<ReflectResource>(resource)
+            else {
+                return false;
+            };
+            let Ok(mut root) = reflect_resource.reflect_mut(world) else {
+                return false;
+            };
+            apply_at_path(root.as_partial_reflect_mut(), path, value)
+        }
+        EditTarget::Asset { asset_type, id } => {
+            let Some(reflect_asset) = type_registry.get_type_data::<ReflectAsset>(asset_type)
+            else {
+                return false;
+            };
+            let Some(root) = reflect_asset.get_mut(world, UntypedHandle::Weak(id)) else {
+                return false;
+            };
+            apply_at_path(root.as_partial_reflect_mut(), path, value)
+        }
+        EditTarget::State(state) => {
+            let (Some(reflect_state), Some(reflect_freely_mutable_state)) = (
+                type_registry.get_type_data::<ReflectState>(state),
+                type_registry.get_type_data::<ReflectFreelyMutableState>(state),
+            ) else {
+                return false;
+            };
+            let Some(Ok(mut root)) = reflect_state
+                .reflect(world)
+                .map(|current| current.reflect_clone())
+            else {
+                return false;
+            };
+            if !apply_at_path(root.as_partial_reflect_mut(), path, value) {
+                return false;
+            }
+            reflect_freely_mutable_state.set_next_state(world, &*root, &type_registry);
+            true
+        }
+    }
+}
+
+fn apply_at_path(
+    root: &mut dyn PartialReflect,
+    path: &ParsedPath,
+    value: &dyn PartialReflect,
+) -> bool {
+    match path.reflect_element_mut(root) {
+        Ok(element) => element.try_apply(value).is_ok(),
+        Err(_) => false,
+    }
+}
+
+fn recording_id() -> egui::Id {
+    egui::Id::new("bevy_inspector_egui::edit_recording")
+}
+
+/// A value edited by a widget, identified by its address so that its path can be found from the displayed root later.
+struct ValueEdit {
+    address: usize,
+    type_id: Option<TypeId>,
+    old: Box<dyn PartialReflect>,
+}
+
+/// The edits collected while an [`EditRecording`] is active, stored in egui's memory
+#[derive(Clone, Default)]
+struct Recording(Arc<Mutex<Vec<ValueEdit>>>);
+
+/// Collects the edits made by widgets while a component, resource, asset or state is displayed.
+///
+/// Instead of copying the whole displayed value, every widget snapshots only the value it edits (see [`ValueSnapshot`]).
+pub(crate) struct EditRecording;
+
+impl EditRecording {
+    pub(crate) fn start(ui: &egui::Ui) -> Self {
+        ui.data_mut(|data| data.insert_temp(recording_id(), Recording::default()));
+        EditRecording
+    }
+
+    pub(crate) fn stop(self, ui: &egui::Ui) -> RecordedEdits {
+        let edits = ui
+            .data_mut(|data| data.remove_temp::<Recording>(recording_id()))
+            .map(|recording| std::mem::take(&mut *recording.0.lock().unwrap()))
+            .unwrap_or_default();
+        RecordedEdits(edits)
+    }
+}
+
+/// The edits made while an [`EditRecording`] was active
+pub(crate) struct RecordedEdits(Vec<ValueEdit>);
+
+impl RecordedEdits {
+    /// Find the recorded edits in `value` and queue a [`HistoryEntry`] and an [`InspectorEdit`] for each changed field.
+    ///
+    /// Must be called whenever `value` was changed. If none of the changes can be found, an [`InspectorEdit`]
+    /// of the whole value is queued instead, so that every edit is reported.
+    pub(crate) fn record(
+        self,
+        ui: &egui::Ui,
+        value: &dyn PartialReflect,
+        target: EditTarget,
+        queue: Option<&mut CommandQueue>,
+    ) {
+        let Some(queue) = queue else {
+            return;
+        };
+
+        let time = ui.input(|input| input.time);
+        let entries: Vec<_> = self
+            .changes(value)
+            .into_iter()
+            .map(|(path, old, new)| HistoryEntry {
+                target,
+                path,
+                old,
+                new,
+                time,
+            })
+            .collect();
+        if entries.is_empty() {
+            let edit = InspectorEdit {
+                target,
+                path: ParsedPath::from(Vec::<Access>::new()),
+                old: None,
+                new: clone_value(value),
+            };
+            queue.push(move |world: &mut World| edit.send(world));
+            return;
+        }
+
+        queue.push(move |world: &mut World| {
+            for entry in entries {
+                let edit = InspectorEdit::from_entry(&entry, false);
+                if let Some(mut history) = world.get_resource_mut::<InspectorHistory>() {
+                    history.record(entry);
+                }
+                edit.send(world);
+            }
+        });
+    }
+
+    /// The path, old and new value of every recorded edit which can be found in `root`.
+    ///
+    /// Edits nested in another recorded edit are covered by the outer one and skipped.
+    fn changes(
+        self,
+        root: &dyn PartialReflect,
+    ) -> Vec<(ParsedPath, Box<dyn PartialReflect>, Box<dyn PartialReflect>)> {
+        let mut edits: Vec<_> = self
+            .0
+            .into_iter()
+            .filter_map(|edit| {
+                let mut path = Vec::new();
+                find_path(root, edit.address, edit.type_id, &mut path).then_some((path, edit.old))
+            })
+            .collect();
+        edits.sort_by_key(|(path, _)| path.len());
+
+        let mut changes: Vec<(Vec<Access<'static>>, _, _)> = Vec::new();
+        for (path, old) in edits {
+            if changes
+                .iter()
+                .any(|(outer, ..)| path.starts_with(outer.as_slice()))
+            {
+                continue;
+            }
+
+            let Ok(new) = ParsedPath::from(path.clone()).reflect_element(root) else {
+                continue;
+            };
+            let inner = changed_path(&*old, new);
+            let inner_path = ParsedPath::from(inner.clone());
+            let (Ok(old), Ok(new)) = (
+                (&inner_path).reflect_element(&*old),
+                (&inner_path).reflect_element(new),
+            ) else {
+                continue;
+            };
+            if old.reflect_partial_eq(new) == Some(true) {
+                continue;
+            }
+            let (Some(old), Some(new)) = (clone_value(old), clone_value(new)) else {
+                continue;
+            };
+
+            let mut full_path = path;
+            full_path.extend(inner);
+            changes.push((full_path, old, new));
+        }
+
+        changes
+            .into_iter()
+            .map(|(path, old, new)| (ParsedPath::from(path), old, new))
+            .collect()
+    }
+}
+
+/// A copy of a single value taken before a widget edits it, added to the active [`EditRecording`] if it changed.
+pub(crate) struct ValueSnapshot(Option<(Recording, ValueEdit)>);
+
+impl ValueSnapshot {
+    /// Snapshot `value` before displaying its widget, if edits are being recorded and there is any input this frame that could lead to an edit.
+    pub(crate) fn take(ui: &egui::Ui, value: &dyn PartialReflect) -> Self {
+        if !has_edit_input(ui) {
+            return ValueSnapshot(None);
+        }
+        ValueSnapshot::before_edit(ui, value)
+    }
+
+    /// Snapshot `value` right before applying an edit to it, if edits are being recorded.
+    pub(crate) fn before_edit(ui: &egui::Ui, value: &dyn PartialReflect) -> Self {
+        let Some(recording) = ui.data(|data| data.get_temp::<Recording>(recording_id())) else {
+            return ValueSnapshot(None);
+        };
+        ValueSnapshot(clone_value(value).map(|old| {
+            let edit = ValueEdit {
+                address: address_of(value),
+                type_id: type_id_of(value),
+                old,
+            };
+            (recording, edit)
+        }))
+    }
+
+    /// Add the snapshot to the recording if `value` was changed.
+    pub(crate) fn record(self, changed: bool) {
+        if let (true, Some((recording, edit))) = (changed, self.0) {
+            recording.0.lock().unwrap().push(edit);
+        }
+    }
+}
+
+fn address_of(value: &dyn PartialReflect) -> usize {
+    (value as *const dyn PartialReflect).cast::<()>() as usize
+}
+
+fn type_id_of(value: &dyn PartialReflect) -> Option<TypeId> {
+    value.get_represented_type_info().map(|info| info.type_id())
+}
+
+/// Search `value` for the value at `address`, pushing the accesses leading to it onto `path`.
+fn find_path(
+    value: &dyn PartialReflect,
+    address: usize,
+    type_id: Option<TypeId>,
+    path: &mut Vec<Access<'static>>,
+) -> bool {
+    if address_of(value) == address && type_id_of(value) == type_id {
+        return true;
+    }
+
+    let mut search = |access: Access<'static>, field: Option<&dyn PartialReflect>| {
+        let Some(field) = field else {
+            return false;
+        };
+        path.push(access);
+        if find_path(field, address, type_id, path) {
+            return true;
+        }
+        path.pop();
+        false
+    };
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => (0..value.field_len()).any(|i| {
+            value.name_at(i).is_some_and(|name| {
+                search(Access::Field(name.to_owned().into()), value.field_at(i))
+            })
+        }),
+        ReflectRef::TupleStruct(value) => {
+            (0..value.field_len()).any(|i| search(Access::TupleIndex(i), value.field(i)))
+        }
+        ReflectRef::Tuple(value) => {
+            (0..value.field_len()).any(|i| search(Access::TupleIndex(i), value.field(i)))
+        }
+        ReflectRef::List(value) => {
+            (0..value.len()).any(|i| search(Access::ListIndex(i), value.get(i)))
+        }
+        ReflectRef::Array(value) => {
+            (0..value.len()).any(|i| search(Access::ListIndex(i), value.get(i)))
+        }
+        ReflectRef::Enum(value) => (0..value.field_len()).any(|i| {
+            let access = match value.name_at(i) {
+                Some(name) => Access::Field(name.to_owned().into()),
+                None => Access::TupleIndex(i),
+            };
+            search(access, value.field_at(i))
+        }),
+        _ => false,
+    }
+}
+
+fn has_edit_input(ui: &egui::Ui) -> bool {
+    ui.input(|input| {
+        input.pointer.any_down()
+            || input.pointer.any_released()
+            || input.events.iter().any(|event| {
+                matches!(
+                    event,
+                    egui::Event::Key { .. }
+                        | egui::Event::Text(_)
+                        | egui::Event::Paste(_)
+                        | egui::Event::MouseWheel { .. }
+                )
+            })
+    })
+}
+
+fn clone_value(value: &dyn PartialReflect) -> Option<Box<dyn PartialReflect>> {
+    match value.reflect_clone() {
+        Ok(value) => Some(value.into_partial_reflect()),
+        // `to_dynamic` panics for opaque values that can't be cloned
+        Err(_) if matches!(value.reflect_ref(), ReflectRef::Opaque(_)) => None,
+        Err(_) => Some(value.to_dynamic()),
+    }
+}
+
+/// Walk down `old` and `new` as long as exactly one field differs, returning the path to the deepest changed value.
+fn changed_path(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Vec<Access<'static>> {
+    let mut path = Vec::new();
+    let (mut old, mut new) = (old, new);
+    while let Some((access, old_field, new_field)) = single_changed_field(old, new) {
+        path.push(access);
+        old = old_field;
+        new = new_field;
+    }
+    path
+}
+
+fn single_changed_field<'a>(
+    old: &'a dyn PartialReflect,
+    new: &'a dyn PartialReflect,
+) -> Option<(
+    Access<'static>,
+    &'a dyn PartialReflect,
+    &'a dyn PartialReflect,
+)> {
+    let fields: Vec<(Access<'static>, &dyn PartialReflect, &dyn PartialReflect)> =
+        match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old), ReflectRef::Struct(new)) => (0..old.field_len())
+                .map(|i| {
+                    let name = old.name_at(i)?;
+                    Some((
+                        Access::Field(name.to_owned().into()),
+                        old.field_at(i)?,
+                        new.field(name)?,
+                    ))
+                })
+                .collect::<Option<_>>()?,
+            (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) => (0..old.field_len())
+                .map(|i| Some((Access::TupleIndex(i), old.field(i)?, new.field(i)?)))
+                .collect::<Option<_>>()?,
+            (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) => (0..old.field_len())
+                .map(|i| Some((Access::TupleIndex(i), old.field(i)?, new.field(i)?)))
+                .collect::<Option<_>>()?,
+            (ReflectRef::List(old), ReflectRef::List(new)) if old.len() == new.len() => (0..old
+                .len())
+                .map(|i| Some((Access::ListIndex(i), old.get(i)?, new.get(i)?)))
+                .collect::<Option<_>>()?,
+            (ReflectRef::Array(old), ReflectRef::Array(new)) if old.len() == new.len() => (0..old
+                .len())
+                .map(|i| Some((Access::ListIndex(i), old.get(i)?, new.get(i)?)))
+                .collect::<Option<_>>()?,
+            (ReflectRef::Enum(old), ReflectRef::Enum(new))
+                if old.variant_name() == new.variant_name() =>
+            {
+                (0..old.field_len())
+                    .map(|i| {
+                        let access = match old.name_at(i) {
+                            Some(name) => Access::Field(name.to_owned().into()),
+                            None => Access::TupleIndex(i),
+                        };
+                        Some((access, old.field_at(i)?, new.field_at(i)?))
+                    })
+                    .collect::<Option<_>>()?
+            }
+            _ => return None,
+        };
+
+    let mut changed = fields
+        .into_iter()
+        .filter(|(_, old, new)| old.reflect_partial_eq(*new) != Some(true));
+    let field = changed.next()?;
+    changed.next().is_none().then_some(field)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::Reflect;
+
+    use super::*;
+
+    #[derive(Reflect, Clone, Default)]
+    struct Inner {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Reflect, Clone, Default)]
+    struct Outer {
+        inner: Inner,
+        count: u32,
+        list: Vec<u32>,
+    }
+
+    fn path(path: &'static str) -> Vec<Access<'static>> {
+        ParsedPath::parse_static(path)
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|element| element.access)
+            .collect()
+    }
+
+    fn entry(field: &'static str, new: f32, time: f64) -> HistoryEntry {
+        HistoryEntry {
+            target: EditTarget::Resource(TypeId::of::<Outer>()),
+            path: ParsedPath::parse_static(field).unwrap(),
+            old: Box::new(0.0f32),
+            new: Box::new(new),
+            time,
+        }
+    }
+
+    /// Runs `f` inside a single egui frame, with `events` as this frame's input
+    fn with_ui(events: Vec<egui::Event>, f: impl FnOnce(&mut egui::Ui)) {
+        let ctx = egui::Context::default();
+        let input = egui::RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut f = Some(f);
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| f.take().unwrap()(ui));
+        });
+    }
+
+    #[test]
+    fn changed_path_finds_deepest_single_change() {
+        let old = Outer::default();
+
+        let mut new = old.clone();
+        new.inner.x = 1.0;
+        assert_eq!(changed_path(&old, &new), path(".inner.x"));
+
+        new.inner.y = 1.0;
+        assert_eq!(changed_path(&old, &new), path(".inner"));
+
+        new.count = 1;
+        assert_eq!(changed_path(&old, &new), Vec::new());
+
+        let mut new = old.clone();
+        new.list.push(1);
+        assert_eq!(changed_path(&old, &new), path(".list"));
+    }
+
+    #[test]
+    fn record_merges_edits_of_the_same_field() {
+        let mut history = InspectorHistory::default();
+        history.record(entry(".inner.x", 1.0, 0.0));
+        history.record(entry(".inner.x", 2.0, MERGE_WINDOW / 2.0));
+        assert_eq!(history.undo_stack().len(), 1);
+        assert_eq!(
+            history.undo_stack()[0].new.try_downcast_ref::<f32>(),
+            Some(&2.0)
+        );
+
+        history.record(entry(".inner.y", 3.0, MERGE_WINDOW / 2.0));
+        history.record(entry(".inner.y", 4.0, MERGE_WINDOW * 2.0));
+        assert_eq!(history.undo_stack().len(), 3);
+    }
+
+    #[test]
+    fn record_trims_to_max_len() {
+        let mut history = InspectorHistory {
+            max_len: 3,
+            ..Default::default()
+        };
+        for i in 0..5 {
+            history.record(entry(".inner.x", i as f32, i as f64 * MERGE_WINDOW * 2.0));
+        }
+        let values: Vec<_> = history
+            .undo_stack()
+            .iter()
+            .map(|entry| *entry.new.try_downcast_ref::<f32>().unwrap())
+            .collect();
+        assert_eq!(values, [2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn snapshots_need_an_active_recording() {
+        let value = Outer::default();
+        with_ui(vec![egui::Event::Text("a".into())], |ui| {
+            assert!(ValueSnapshot::before_edit(ui, &value).0.is_none());
+
+            let recording = EditRecording::start(ui);
+            assert!(ValueSnapshot::before_edit(ui, &value).0.is_some());
+            recording.stop(ui);
+        });
+    }
+
+    #[test]
+    fn snapshots_are_only_taken_with_input() {
+        let value = Outer::default();
+        with_ui(Vec::new(), |ui| {
+            let recording = EditRecording::start(ui);
+            assert!(ValueSnapshot::take(ui, &value).0.is_none());
+            recording.stop(ui);
+        });
+    }
+
+    #[test]
+    fn recorded_edit_is_found_in_the_root() {
+        let mut world = World::new();
+        world.init_resource::<InspectorHistory>();
+        let mut queue = CommandQueue::default();
+
+        let mut value = Outer::default();
+        with_ui(vec![egui::Event::Text("a".into())], |ui| {
+            let recording = EditRecording::start(ui);
+            // the widget for `Inner` only changes `x`
+            let snapshot = ValueSnapshot::take(ui, &value.inner);
+            value.inner.x = 1.0;
+            snapshot.record(true);
+            // a value which is not part of the root is ignored
+            let unrelated = Inner::default();
+            ValueSnapshot::take(ui, &unrelated).record(true);
+            let edits = recording.stop(ui);
+
+            edits.record(
+                ui,
+                &value,
+                EditTarget::Resource(TypeId::of::<Outer>()),
+                Some(&mut queue),
+            );
+        });
+        queue.apply(&mut world);
+
+        let history = world.resource::<InspectorHistory>();
+        let [entry] = history.undo_stack() else {
+            panic!("expected one entry, got {:?}", history.undo_stack());
+        };
+        assert_eq!(entry.path, ParsedPath::from(path(".inner.x")));
+        assert_eq!(entry.old.try_downcast_ref::<f32>(), Some(&0.0));
+        assert_eq!(entry.new.try_downcast_ref::<f32>(), Some(&1.0));
+    }
+
+    #[test]
+    fn nested_edits_are_covered_by_the_outer_edit() {
+        let mut value = Outer::default();
+        let edits = Recording::default();
+        let recorded = |value: &dyn PartialReflect| ValueEdit {
+            address: address_of(value),
+            type_id: type_id_of(value),
+            old: clone_value(value).unwrap(),
+        };
+        let outer = recorded(&value.inner);
+        let inner = recorded(&value.inner.y);
+        value.inner.x = 1.0;
+        value.inner.y = 1.0;
+        edits.0.lock().unwrap().extend([inner, outer]);
+
+        let changes = RecordedEdits(std::mem::take(&mut *edits.0.lock().unwrap())).changes(&value);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].0, ParsedPath::from(path(".inner")));
+    }
+
+    fn sent_edits(world: &World) -> Vec<&InspectorEdit> {
+        world
+            .resource::<Events<InspectorEdit>>()
+            .iter_current_update_events()
+            .collect()
+    }
+
+    #[test]
+    fn edit_without_history_is_sent() {
+        let mut world = World::new();
+        world.init_resource::<Events<InspectorEdit>>();
+        let mut queue = CommandQueue::default();
+
+        let mut value = Outer::default();
+        with_ui(vec![egui::Event::Text("a".into())], |ui| {
+            let recording = EditRecording::start(ui);
+            let snapshot = ValueSnapshot::take(ui, &value.count);
+            value.count = 1;
+            snapshot.record(true);
+            let edits = recording.stop(ui);
+
+            edits.record(
+                ui,
+                &value,
+                EditTarget::Resource(TypeId::of::<Outer>()),
+                Some(&mut queue),
+            );
+        });
+        queue.apply(&mut world);
+
+        let [edit] = sent_edits(&world)[..] else {
+            panic!("expected one edit");
+        };
+        assert_eq!(edit.path, ParsedPath::from(path(".count")));
+        let old = edit
+            .old
+            .as_ref()
+            .and_then(|old| old.try_downcast_ref::<u32>());
+        assert_eq!(old, Some(&0));
+    }
+
+    #[test]
+    fn edit_without_snapshot_is_sent_for_the_whole_value() {
+        let mut world = World::new();
+        world.init_resource::<InspectorHistory>();
+        world.init_resource::<Events<InspectorEdit>>();
+        let mut queue = CommandQueue::default();
+
+        let mut value = Outer::default();
+        // no input, so widgets don't take snapshots
+        with_ui(Vec::new(), |ui| {
+            let recording = EditRecording::start(ui);
+            let snapshot = ValueSnapshot::take(ui, &value.count);
+            value.count = 1;
+            snapshot.record(true);
+            let edits = recording.stop(ui);
+
+            edits.record(
+                ui,
+                &value,
+                EditTarget::Resource(TypeId::of::<Outer>()),
+                Some(&mut queue),
+            );
+        });
+        queue.apply(&mut world);
+
+        assert!(world.resource::<InspectorHistory>().undo_stack().is_empty());
+        let [edit] = sent_edits(&world)[..] else {
+            panic!("expected one edit");
+        };
+        assert_eq!(edit.path, ParsedPath::from(Vec::<Access>::new()));
+        assert!(edit.old.is_none());
+        let new = edit
+            .new
+            .as_ref()
+            .and_then(|new| new.try_downcast_ref::<Outer>());
+        assert_eq!(new.map(|new| new.count), Some(1));
+    }
+}
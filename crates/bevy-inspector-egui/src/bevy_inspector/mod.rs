@@ -45,7 +45,7 @@ use bevy_asset::{Asset, AssetServer, Assets, ReflectAsset, UntypedAssetId};
 use bevy_ecs::query::{QueryFilter, WorldQuery};
 use bevy_ecs::world::CommandQueue;
 use bevy_ecs::{component::ComponentId, prelude::*};
-use bevy_reflect::{Reflect, TypeRegistry};
+use bevy_reflect::{PartialReflect, Reflect, TypeRegistry};
 use bevy_state::state::{FreelyMutableState, NextState, State};
 use fuzzy_matcher::FuzzyMatcher;
 use fuzzy_matcher::skim::SkimMatcherV2;
@@ -55,6 +55,8 @@ pub(crate) mod errors;
 /// UI for displaying the entity hierarchy
 pub mod hierarchy;
 
+pub mod history;
+
 use crate::reflect_inspector::{Context, InspectorUi};
 use crate::restricted_world_view::{ReflectBorrow, RestrictedWorldView};
 
@@ -110,14 +112,77 @@ pub fn ui_for_resources(world: &mut World, ui: &mut egui::Ui) {
             )
         })
         .collect();
-    resources.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    resources.sort_by_key(|(name, ..)| *name);
     for (name, type_id) in resources {
-        ui.collapsing(name, |ui| {
+        let response = ui.collapsing(name, |ui| {
             by_type_id::ui_for_resource(world, type_id, ui, name, &type_registry);
         });
+        if header_context_menu_active(&response.header_response) {
+            resource_header_context_menu(
+                world,
+                type_id,
+                ui,
+                &response.header_response,
+                &type_registry,
+            );
+        }
     }
 }
 
+/// Whether the context menu of a header is being opened or open, so that the value only has to be accessed then
+fn header_context_menu_active(response: &egui::Response) -> bool {
+    response.secondary_clicked() || response.context_menu_opened()
+}
+
+/// Right click menu on the header of a component, resource or asset to copy, paste or reset its value,
+/// see [`InspectorUi::value_context_menu`]. The edit is recorded in the [`history`].
+fn header_context_menu(
+    env: &mut InspectorUi,
+    ui: &egui::Ui,
+    response: &egui::Response,
+    value: &mut dyn PartialReflect,
+    target: history::EditTarget,
+) -> bool {
+    let recording = history::EditRecording::start(ui);
+    let changed = env.value_context_menu(response, value);
+    let edits = recording.stop(ui);
+    if changed {
+        edits.record(ui, value, target, env.context.queue.as_deref_mut());
+    }
+    changed
+}
+
+fn resource_header_context_menu(
+    world: &mut World,
+    type_id: TypeId,
+    ui: &egui::Ui,
+    response: &egui::Response,
+    type_registry: &TypeRegistry,
+) {
+    let Some(reflect_resource) = type_registry.get_type_data::<ReflectResource>(type_id) else {
+        return;
+    };
+    let mut queue = CommandQueue::default();
+    if let Ok(mut resource) = reflect_resource.reflect_mut(&mut *world) {
+        let mut cx = Context {
+            world: None,
+            queue: Some(&mut queue),
+        };
+        let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+        let changed = header_context_menu(
+            &mut env,
+            ui,
+            response,
+            resource.bypass_change_detection().as_partial_reflect_mut(),
+            history::EditTarget::Resource(type_id),
+        );
+        if changed {
+            resource.set_changed();
+        }
+    }
+    queue.apply(world);
+}
+
 /// Display the resource `R`
 pub fn ui_for_resource<R: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
@@ -137,8 +202,17 @@ pub fn ui_for_resource<R: Resource + Reflect>(world: &mut World, ui: &mut egui::
     };
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
 
-    if env.ui_for_reflect(resource.bypass_change_detection(), ui) {
+    let recording = history::EditRecording::start(ui);
+    let changed = env.ui_for_reflect(resource.bypass_change_detection(), ui);
+    let edits = recording.stop(ui);
+    if changed {
         resource.set_changed();
+        edits.record(
+            ui,
+            resource.as_partial_reflect(),
+            history::EditTarget::Resource(TypeId::of::<R>()),
+            cx.queue.as_deref_mut(),
+        );
     }
 
     queue.apply(world);
@@ -159,7 +233,7 @@ pub fn ui_for_all_assets(world: &mut World, ui: &mut egui::Ui) {
             )
         })
         .collect();
-    assets.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    assets.sort_by_key(|(name, ..)| *name);
     for (name, type_id) in assets {
         ui.collapsing(name, |ui| {
             by_type_id::ui_for_assets(world, type_id, ui, &type_registry);
@@ -189,16 +263,36 @@ pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
     };
 
     let mut assets: Vec<_> = assets.iter_mut().collect();
-    assets.sort_by(|(a, _), (b, _)| a.cmp(b));
+    assets.sort_by_key(|(id, _)| *id);
     for (handle_id, asset) in assets {
         let id = egui::Id::new(handle_id);
 
-        egui::CollapsingHeader::new(handle_name(handle_id.untyped(), asset_server.as_ref()))
-            .id_salt(id)
-            .show(ui, |ui| {
-                let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
-                env.ui_for_reflect_with_options(asset, ui, id, &());
-            });
+        let target = history::EditTarget::Asset {
+            asset_type: TypeId::of::<A>(),
+            id: handle_id.untyped(),
+        };
+        let response =
+            egui::CollapsingHeader::new(handle_name(handle_id.untyped(), asset_server.as_ref()))
+                .id_salt(id)
+                .show(ui, |ui| {
+                    let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+                    let recording = history::EditRecording::start(ui);
+                    let changed = env.ui_for_reflect_with_options(asset, ui, id, &());
+                    let edits = recording.stop(ui);
+                    if changed {
+                        edits.record(
+                            ui,
+                            asset.as_partial_reflect(),
+                            target,
+                            cx.queue.as_deref_mut(),
+                        );
+                    }
+                });
+
+        if header_context_menu_active(&response.header_response) {
+            let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+            header_context_menu(&mut env, ui, &response.header_response, asset, target);
+        }
     }
 
     queue.apply(world);
@@ -229,9 +323,17 @@ pub fn ui_for_state<T: FreelyMutableState + Reflect>(world: &mut World, ui: &mut
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
 
     let mut current = state.get().clone();
+    let recording = history::EditRecording::start(ui);
     let changed = env.ui_for_reflect(&mut current, ui);
+    let edits = recording.stop(ui);
 
     if changed {
+        edits.record(
+            ui,
+            &current,
+            history::EditTarget::State(TypeId::of::<T>()),
+            Some(&mut queue),
+        );
         *next_state = NextState::Pending(current);
     }
     queue.apply(world);
@@ -606,7 +708,7 @@ pub(crate) fn ui_for_entity_components(
             queue: queue.as_deref_mut(),
         };
 
-        let value = match component_view.get_entity_component_reflect(
+        let mut value = match component_view.get_entity_component_reflect(
             entity,
             component_type_id,
             type_registry,
@@ -626,24 +728,36 @@ pub(crate) fn ui_for_entity_components(
             set_highlight_style(ui);
         }
 
-        let _response = header.show(ui, |ui| {
+        let target = history::EditTarget::Component {
+            entity,
+            component: component_type_id,
+        };
+        let response = header.show(ui, |ui| {
             ui.reset_style();
 
             let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
             let id = id.with(component_id);
             let options = &();
 
-            match value {
-                ReflectBorrow::Mutable(mut value) => {
+            match &mut value {
+                ReflectBorrow::Mutable(value) => {
+                    let recording = history::EditRecording::start(ui);
                     let changed = env.ui_for_reflect_with_options(
                         value.bypass_change_detection().as_partial_reflect_mut(),
                         ui,
                         id,
                         options,
                     );
+                    let edits = recording.stop(ui);
 
                     if changed {
                         value.set_changed();
+                        edits.record(
+                            ui,
+                            value.as_partial_reflect(),
+                            target,
+                            cx.queue.as_deref_mut(),
+                        );
                     }
                 }
                 ReflectBorrow::Immutable(value) => env.ui_for_reflect_readonly_with_options(
@@ -655,8 +769,26 @@ pub(crate) fn ui_for_entity_components(
             };
         });
 
+        let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+        match &mut value {
+            ReflectBorrow::Mutable(value) => {
+                let changed = header_context_menu(
+                    &mut env,
+                    ui,
+                    &response.header_response,
+                    value.bypass_change_detection().as_partial_reflect_mut(),
+                    target,
+                );
+                if changed {
+                    value.set_changed();
+                }
+            }
+            ReflectBorrow::Immutable(value) => env
+                .value_context_menu_readonly(&response.header_response, value.as_partial_reflect()),
+        }
+
         #[cfg(feature = "documentation")]
-        crate::egui_utils::show_docs(_response.header_response, type_docs);
+        crate::egui_utils::show_docs(response.header_response, type_docs);
         ui.reset_style();
     }
 }
@@ -804,7 +936,7 @@ pub fn ui_for_entities_shared_components(
 pub mod by_type_id {
     use std::any::TypeId;
 
-    use bevy_asset::{AssetServer, ReflectAsset, ReflectHandle, UntypedAssetId, UntypedHandle};
+    use bevy_asset::{AssetServer, ReflectAsset, UntypedAssetId, UntypedHandle};
     use bevy_ecs::{prelude::*, world::CommandQueue};
     use bevy_reflect::TypeRegistry;
 
@@ -815,7 +947,7 @@ pub mod by_type_id {
 
     use super::{
         errors::{self, name_of_type},
-        handle_name,
+        handle_name, header_context_menu, header_context_menu_active, history,
     };
 
     /// Display the resource with the given [`TypeId`]
@@ -845,12 +977,20 @@ pub mod by_type_id {
                 Err(err) => return errors::show_error(err, ui, name_of_type),
             };
 
+            let recording = history::EditRecording::start(ui);
             let changed = env.ui_for_reflect(
                 resource.bypass_change_detection().as_partial_reflect_mut(),
                 ui,
             );
+            let edits = recording.stop(ui);
             if changed {
                 resource.set_changed();
+                edits.record(
+                    ui,
+                    resource.as_partial_reflect(),
+                    history::EditTarget::Resource(resource_type_id),
+                    cx.queue.as_deref_mut(),
+                );
             }
         }
 
@@ -879,21 +1019,12 @@ pub mod by_type_id {
                 "ReflectAsset",
             );
         };
-        let Some(reflect_handle) =
-            type_registry.get_type_data::<ReflectHandle>(reflect_asset.handle_type_id())
-        else {
-            return errors::no_type_data(
-                ui,
-                &name_of_type(reflect_asset.handle_type_id(), type_registry),
-                "ReflectHandle",
-            );
-        };
-
         let ids: Vec<_> = reflect_asset.ids(world).collect();
 
-        // Create a context with access to the entire world. Displaying the `Handle<T>` will short circuit into
-        // displaying the T with a world view excluding Assets<T>.
-        let world_view = RestrictedWorldView::new(world);
+        // Create a context with access to the world except for `Assets<T>`, the assets are edited directly
+        let mut world_view = RestrictedWorldView::new(world);
+        let (assets_view, world_view) =
+            world_view.split_off_resource(reflect_asset.assets_resource_type_id());
         let mut queue = CommandQueue::default();
         let mut cx = Context {
             world: Some(world_view),
@@ -902,16 +1033,52 @@ pub mod by_type_id {
 
         for handle_id in ids {
             let id = egui::Id::new(handle_id);
-            let mut handle = reflect_handle
-                .typed(UntypedHandle::Weak(handle_id))
-                .into_partial_reflect();
+            // SAFETY: `assets_view` has mutable access to `Assets<T>`, which the context can't access,
+            // and only one asset is borrowed at a time
+            let asset = unsafe {
+                reflect_asset.get_unchecked_mut(assets_view.world(), UntypedHandle::Weak(handle_id))
+            };
+            let Some(asset) = asset else {
+                continue;
+            };
+            let target = history::EditTarget::Asset {
+                asset_type: asset_type_id,
+                id: handle_id,
+            };
 
-            egui::CollapsingHeader::new(handle_name(handle_id, asset_server.as_ref()))
-                .id_salt(id)
-                .show(ui, |ui| {
-                    let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
-                    env.ui_for_reflect_with_options(&mut *handle, ui, id, &());
-                });
+            let response =
+                egui::CollapsingHeader::new(handle_name(handle_id, asset_server.as_ref()))
+                    .id_salt(id)
+                    .show(ui, |ui| {
+                        let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+                        let recording = history::EditRecording::start(ui);
+                        let changed = env.ui_for_reflect_with_options(
+                            asset.as_partial_reflect_mut(),
+                            ui,
+                            id,
+                            &(),
+                        );
+                        let edits = recording.stop(ui);
+                        if changed {
+                            edits.record(
+                                ui,
+                                asset.as_partial_reflect(),
+                                target,
+                                cx.queue.as_deref_mut(),
+                            );
+                        }
+                    });
+
+            if header_context_menu_active(&response.header_response) {
+                let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+                header_context_menu(
+                    &mut env,
+                    ui,
+                    &response.header_response,
+                    asset.as_partial_reflect_mut(),
+                    target,
+                );
+            }
         }
 
         queue.apply(world)
@@ -940,35 +1107,41 @@ pub mod by_type_id {
             );
             return false;
         };
-        let Some(reflect_handle) =
-            type_registry.get_type_data::<ReflectHandle>(reflect_asset.handle_type_id())
-        else {
-            errors::no_type_data(
-                ui,
-                &name_of_type(reflect_asset.handle_type_id(), type_registry),
-                "ReflectHandle",
-            );
-            return false;
-        };
-
-        let _: Vec<_> = reflect_asset.ids(world).collect();
-
-        // Create a context with access to the entire world. Displaying the `Handle<T>` will short circuit into
-        // displaying the T with a world view excluding Assets<T>.
-        let world_view = RestrictedWorldView::new(world);
+        // Create a context with access to the world except for `Assets<T>`, the asset is edited directly
+        let mut world_view = RestrictedWorldView::new(world);
+        let (assets_view, world_view) =
+            world_view.split_off_resource(reflect_asset.assets_resource_type_id());
         let mut queue = CommandQueue::default();
         let mut cx = Context {
             world: Some(world_view),
             queue: Some(&mut queue),
         };
 
-        let id = egui::Id::new(handle);
-        let mut handle = reflect_handle
-            .typed(UntypedHandle::Weak(handle))
-            .into_partial_reflect();
+        // SAFETY: `assets_view` has mutable access to `Assets<T>`, which the context can't access
+        let asset = unsafe {
+            reflect_asset.get_unchecked_mut(assets_view.world(), UntypedHandle::Weak(handle))
+        };
+        let Some(asset) = asset else {
+            errors::dead_asset_handle(ui, handle);
+            return false;
+        };
 
+        let id = egui::Id::new(handle);
         let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
-        let changed = env.ui_for_reflect_with_options(&mut *handle, ui, id, &());
+        let recording = history::EditRecording::start(ui);
+        let changed = env.ui_for_reflect_with_options(asset.as_partial_reflect_mut(), ui, id, &());
+        let edits = recording.stop(ui);
+        if changed {
+            edits.record(
+                ui,
+                asset.as_partial_reflect(),
+                history::EditTarget::Asset {
+                    asset_type: asset_type_id,
+                    id: handle,
+                },
+                cx.queue.as_deref_mut(),
+            );
+        }
 
         queue.apply(world);
 
@@ -996,17 +1169,13 @@ fn handle_name(handle: UntypedAssetId, asset_server: Option<&AssetServer>) -> St
 
 impl<'a, 'c> InspectorUi<'a, 'c> {
     /// [`InspectorUi`] with short circuiting methods able to display `bevy_asset` [`Handle`](bevy_asset::Handle)s
+    /// and the handlers added to the world using [`short_circuit::add_handler`]
     pub fn for_bevy(
         type_registry: &'a TypeRegistry,
         context: &'a mut Context<'c>,
     ) -> InspectorUi<'a, 'c> {
-        InspectorUi::new(
-            type_registry,
-            context,
-            Some(short_circuit::short_circuit),
-            Some(short_circuit::short_circuit_readonly),
-            Some(short_circuit::short_circuit_many),
-        )
+        let short_circuit = short_circuit::handlers(context);
+        InspectorUi::with_short_circuit(type_registry, context, short_circuit)
     }
 }
 
@@ -1014,12 +1183,80 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
 pub mod short_circuit {
     use std::any::{Any, TypeId};
 
-    use bevy_asset::ReflectAsset;
-    use bevy_reflect::PartialReflect;
+    use bevy_asset::{
+        AssetPath, AssetServer, Handle, LoadState, LoadedUntypedAsset, ReflectAsset, ReflectHandle,
+        UntypedAssetId, UntypedHandle,
+    };
+    use bevy_ecs::world::{CommandQueue, World};
+    use bevy_reflect::{PartialReflect, TypeRegistry, std_traits::ReflectDefault};
 
-    use crate::reflect_inspector::{Context, InspectorUi, ProjectorReflect};
+    use crate::{
+        dropdown::DropDownBox,
+        reflect_inspector::{
+            Context, InspectorUi, ProjectorReflect, ShortCircuitHandler, ShortCircuitHandlers,
+        },
+        restricted_world_view::RestrictedWorldView,
+    };
 
     use super::errors::{self, name_of_type};
+    use super::{handle_name, history};
+
+    /// Priority of the [`Handle`](bevy_asset::Handle) handler in [`default_handlers`].
+    pub const HANDLE_PRIORITY: i32 = 0;
+
+    /// [`ShortCircuitHandler`] displaying [`Handle`](bevy_asset::Handle)s as their asset value.
+    ///
+    /// A toggle switches between the inline contents of the asset and a reference view,
+    /// which lets you pick another loaded asset, load a new path or create a default asset.
+    pub fn handle_handler() -> ShortCircuitHandler {
+        ShortCircuitHandler::from_fns(
+            Some(short_circuit),
+            Some(short_circuit_readonly),
+            Some(short_circuit_many),
+        )
+    }
+
+    /// The default handlers used by [`InspectorUi::for_bevy`].
+    pub fn default_handlers() -> ShortCircuitHandlers {
+        ShortCircuitHandlers::default().with(HANDLE_PRIORITY, handle_handler())
+    }
+
+    /// The handlers used by [`InspectorUi::for_bevy`]: the [`ShortCircuitHandlers`] resource containing the [`default_handlers`]
+    /// and the ones added with [`add_handler`], or just the [`default_handlers`] if the context has no access to it.
+    pub fn handlers(context: &mut Context) -> ShortCircuitHandlers {
+        context
+            .world
+            .as_mut()
+            .and_then(|world| world.get_resource_mut::<ShortCircuitHandlers>().ok())
+            .map(|handlers| handlers.clone())
+            .unwrap_or_else(default_handlers)
+    }
+
+    /// Adds a handler to the ones used by [`InspectorUi::for_bevy`], and therefore by the functions in
+    /// [`bevy_inspector`](crate::bevy_inspector) and the [`quick`](crate::quick) plugins.
+    ///
+    /// The handlers are stored in the [`ShortCircuitHandlers`] resource, which is inserted with the [`default_handlers`] if missing.
+    /// Use a priority above or below [`HANDLE_PRIORITY`] to run before or after the handle handler.
+    /// ```rust
+    /// use bevy_app::App;
+    /// use bevy_inspector_egui::bevy_inspector::short_circuit;
+    /// use bevy_inspector_egui::reflect_inspector::ShortCircuitHandler;
+    ///
+    /// let mut app = App::new();
+    /// short_circuit::add_handler(
+    ///     app.world_mut(),
+    ///     short_circuit::HANDLE_PRIORITY + 1,
+    ///     ShortCircuitHandler::default().readonly(|_env, value, ui, _id, _options| {
+    ///         ui.monospace(value.try_downcast_ref::<String>()?);
+    ///         Some(())
+    ///     }),
+    /// );
+    /// ```
+    pub fn add_handler(world: &mut World, priority: i32, handler: ShortCircuitHandler) {
+        world
+            .get_resource_or_insert_with(default_handlers)
+            .add(priority, handler);
+    }
 
     pub fn short_circuit(
         env: &mut InspectorUi,
@@ -1028,11 +1265,11 @@ pub mod short_circuit {
         id: egui::Id,
         options: &dyn Any,
     ) -> Option<bool> {
-        let value = value.try_as_reflect()?;
+        let value = value.try_as_reflect_mut()?;
 
         if let Some(reflect_handle) = env
             .type_registry
-            .get_type_data::<bevy_asset::ReflectHandle>(value.type_id())
+            .get_type_data::<ReflectHandle>(value.as_any().type_id())
         {
             let handle = reflect_handle
                 .downcast_handle_untyped(value.as_any())
@@ -1059,9 +1296,32 @@ pub mod short_circuit {
                 return Some(false);
             };
 
-            let (assets_view, world) =
+            let inline = view_toggle(ui, id);
+
+            let (assets_view, mut world) =
                 world.split_off_resource(reflect_asset.assets_resource_type_id());
 
+            if !inline {
+                let new_handle = handle_picker(
+                    ui,
+                    id,
+                    handle_id,
+                    reflect_handle,
+                    reflect_asset,
+                    env.type_registry,
+                    &assets_view,
+                    &mut world,
+                    queue.as_deref_mut(),
+                );
+                let Some(new_handle) = new_handle else {
+                    return Some(false);
+                };
+                let snapshot = history::ValueSnapshot::before_edit(ui, value.as_partial_reflect());
+                let changed = value.set(reflect_handle.typed(new_handle)).is_ok();
+                snapshot.record(changed);
+                return Some(changed);
+            }
+
             let asset_value = {
                 assert!(
                     assets_view.allows_access_to_resource(reflect_asset.assets_resource_type_id())
@@ -1084,9 +1344,7 @@ pub mod short_circuit {
                     world: Some(world),
                     queue: queue.as_deref_mut(),
                 },
-                short_circuit: env.short_circuit,
-                short_circuit_readonly: env.short_circuit_readonly,
-                short_circuit_many: env.short_circuit_many,
+                short_circuit: env.short_circuit.clone(),
             };
             return Some(restricted_env.ui_for_reflect_with_options(
                 asset_value.as_partial_reflect_mut(),
@@ -1109,10 +1367,7 @@ pub mod short_circuit {
         values: &mut [&mut dyn PartialReflect],
         projector: &dyn ProjectorReflect,
     ) -> Option<bool> {
-        if let Some(reflect_handle) = env
-            .type_registry
-            .get_type_data::<bevy_asset::ReflectHandle>(type_id)
-        {
+        if let Some(reflect_handle) = env.type_registry.get_type_data::<ReflectHandle>(type_id) {
             let Some(reflect_asset) = env
                 .type_registry
                 .get_type_data::<ReflectAsset>(reflect_handle.asset_type_id())
@@ -1183,9 +1438,7 @@ pub mod short_circuit {
                     world: Some(world),
                     queue: queue.as_deref_mut(),
                 },
-                short_circuit: env.short_circuit,
-                short_circuit_readonly: env.short_circuit_readonly,
-                short_circuit_many: env.short_circuit_many,
+                short_circuit: env.short_circuit.clone(),
             };
             return Some(restricted_env.ui_for_reflect_many_with_options(
                 reflect_handle.asset_type_id(),
@@ -1212,7 +1465,7 @@ pub mod short_circuit {
 
         if let Some(reflect_handle) = env
             .type_registry
-            .get_type_data::<bevy_asset::ReflectHandle>(value.type_id())
+            .get_type_data::<ReflectHandle>(value.type_id())
         {
             let handle = reflect_handle
                 .downcast_handle_untyped(value.as_any())
@@ -1239,6 +1492,12 @@ pub mod short_circuit {
                 return Some(());
             };
 
+            if !view_toggle(ui, id) {
+                let asset_server = world.get_resource_mut::<AssetServer>().ok();
+                ui.label(handle_name(handle_id, asset_server.as_deref()));
+                return Some(());
+            }
+
             let (assets_view, world) =
                 world.split_off_resource(reflect_asset.assets_resource_type_id());
 
@@ -1265,9 +1524,7 @@ pub mod short_circuit {
                     world: Some(world),
                     queue: queue.as_deref_mut(),
                 },
-                short_circuit: env.short_circuit,
-                short_circuit_readonly: env.short_circuit_readonly,
-                short_circuit_many: env.short_circuit_many,
+                short_circuit: env.short_circuit.clone(),
             };
             restricted_env.ui_for_reflect_readonly_with_options(
                 asset_value,
@@ -1280,6 +1537,205 @@ pub mod short_circuit {
 
         None
     }
+
+    /// A handle requested by the picker which will be assigned in a later frame
+    #[derive(Clone)]
+    enum PendingHandle {
+        Loading {
+            path: AssetPath<'static>,
+            handle: Handle<LoadedUntypedAsset>,
+        },
+        Ready(UntypedHandle),
+        Failed {
+            path: String,
+            reason: String,
+        },
+    }
+
+    /// Shows the toggle between the reference and inline contents view and returns whether the contents should be shown inline
+    fn view_toggle(ui: &mut egui::Ui, id: egui::Id) -> bool {
+        let view_id = id.with("handle_view_inline");
+        let mut inline = ui.data_mut(|data| *data.get_temp_mut_or(view_id, true));
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut inline, false, "Reference")
+                .on_hover_text("Pick the asset this handle points to");
+            ui.selectable_value(&mut inline, true, "Inline")
+                .on_hover_text("Edit the contents of the asset");
+        });
+        ui.data_mut(|data| data.insert_temp(view_id, inline));
+        inline
+    }
+
+    fn handle_picker(
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        current: UntypedAssetId,
+        reflect_handle: &ReflectHandle,
+        reflect_asset: &ReflectAsset,
+        type_registry: &TypeRegistry,
+        assets_view: &RestrictedWorldView,
+        world: &mut RestrictedWorldView,
+        queue: Option<&mut CommandQueue>,
+    ) -> Option<UntypedHandle> {
+        let asset_type_id = reflect_handle.asset_type_id();
+        let asset_server = world.get_resource_mut::<AssetServer>().ok();
+        let asset_server = asset_server.as_deref();
+
+        let asset_ids: Vec<UntypedAssetId> = {
+            // SAFETY: the following code only accesses a resources it has access to, `Assets<T>`
+            let interior_mutable_world = unsafe { assets_view.world().world() };
+            assert!(assets_view.allows_access_to_resource(reflect_asset.assets_resource_type_id()));
+            reflect_asset.ids(interior_mutable_world).collect()
+        };
+        let mut assets: Vec<(String, UntypedAssetId)> = asset_ids
+            .into_iter()
+            .map(|asset_id| (handle_name(asset_id, asset_server), asset_id))
+            .collect();
+        assets.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut new_handle = None;
+
+        // resolve handles requested in a previous frame
+        let pending_id = id.with("pending_handle");
+        match ui.data(|data| data.get_temp::<PendingHandle>(pending_id)) {
+            Some(PendingHandle::Ready(handle)) => {
+                ui.data_mut(|data| data.remove::<PendingHandle>(pending_id));
+                new_handle = Some(handle);
+            }
+            Some(PendingHandle::Loading { path, handle }) => {
+                if let Some(asset_server) = asset_server {
+                    if let Some(handle) =
+                        asset_server.get_path_and_type_id_handle(&path, asset_type_id)
+                    {
+                        ui.data_mut(|data| data.remove::<PendingHandle>(pending_id));
+                        new_handle = Some(handle);
+                    } else {
+                        let reason = match asset_server.load_state(&handle) {
+                            LoadState::Failed(error) => Some(error.to_string()),
+                            LoadState::Loaded => Some(format!(
+                                "it is not a {}",
+                                name_of_type(asset_type_id, type_registry)
+                            )),
+                            LoadState::NotLoaded | LoadState::Loading => None,
+                        };
+                        match reason {
+                            Some(reason) => ui.data_mut(|data| {
+                                data.insert_temp(
+                                    pending_id,
+                                    PendingHandle::Failed {
+                                        path: path.to_string(),
+                                        reason,
+                                    },
+                                )
+                            }),
+                            None => {
+                                ui.horizontal(|ui| {
+                                    ui.spinner();
+                                    ui.label(format!("Loading {path}"));
+                                });
+                                ui.ctx().request_repaint();
+                            }
+                        }
+                    }
+                }
+            }
+            Some(PendingHandle::Failed { path, reason }) => {
+                errors::asset_load_failed(ui, &path, &reason);
+            }
+            None => {}
+        }
+
+        let search_id = id.with("asset_picker_search_text");
+        let mut search_text =
+            ui.data_mut(|data| data.get_temp_mut_or_default::<String>(search_id).clone());
+
+        let mut selected = None;
+        ui.horizontal(|ui| {
+            let dropdown = DropDownBox::from_iter(
+                assets.iter().map(|(name, _)| name),
+                id.with("asset_picker"),
+                &mut search_text,
+                |ui, name| {
+                    let asset_id = assets
+                        .iter()
+                        .find_map(|(other, asset_id)| (other == name).then_some(*asset_id));
+                    // a strong handle can only be created for assets tracked by the `AssetServer`,
+                    // not for assets created with `Assets::add` which only their existing handles keep alive
+                    let handle = asset_id
+                        .zip(asset_server)
+                        .and_then(|(asset_id, asset_server)| {
+                            asset_server.get_id_handle_untyped(asset_id)
+                        });
+                    let is_current = asset_id == Some(current);
+                    let response = ui
+                        .add_enabled(
+                            handle.is_some() || is_current,
+                            egui::SelectableLabel::new(is_current, name),
+                        )
+                        .on_disabled_hover_text(
+                            "Not tracked by the AssetServer, so no strong handle can be created for it",
+                        );
+                    if response.clicked() {
+                        selected = handle;
+                    }
+                    response
+                },
+            )
+            .hint_text(handle_name(current, asset_server));
+            ui.add(dropdown);
+
+            let load = ui
+                .add_enabled(
+                    asset_server.is_some() && !search_text.is_empty(),
+                    egui::Button::new("Load"),
+                )
+                .on_hover_text("Load the entered path using the AssetServer");
+            if load.clicked()
+                && let Some(asset_server) = asset_server
+            {
+                let path = AssetPath::parse(&search_text).into_owned();
+                match asset_server.get_path_and_type_id_handle(&path, asset_type_id) {
+                    Some(handle) => new_handle = Some(handle),
+                    None => {
+                        let handle = asset_server.load_untyped(path.clone());
+                        ui.data_mut(|data| {
+                            data.insert_temp(pending_id, PendingHandle::Loading { path, handle })
+                        });
+                    }
+                }
+            }
+
+            let reflect_default = type_registry.get_type_data::<ReflectDefault>(asset_type_id);
+            let new = ui
+                .add_enabled(
+                    reflect_default.is_some() && queue.is_some(),
+                    egui::Button::new("New"),
+                )
+                .on_hover_text("Add a new default asset")
+                .on_disabled_hover_text("No ReflectDefault is registered for this asset");
+            if new.clicked()
+                && let (Some(reflect_default), Some(queue)) = (reflect_default, queue)
+            {
+                let value = reflect_default.default();
+                let reflect_asset = reflect_asset.clone();
+                let ctx = ui.ctx().clone();
+                queue.push(move |world: &mut World| {
+                    let handle = reflect_asset.add(world, value.as_partial_reflect());
+                    ctx.data_mut(|data| data.insert_temp(pending_id, PendingHandle::Ready(handle)));
+                    ctx.request_repaint();
+                });
+            }
+        });
+
+        ui.data_mut(|data| *data.get_temp_mut_or_default::<String>(search_id) = search_text);
+
+        if let Some(handle) = selected {
+            ui.data_mut(|data| data.remove::<PendingHandle>(pending_id));
+            new_handle = Some(handle);
+        }
+
+        new_handle
+    }
 }
 
 pub use crate::utils::guess_entity_name::guess_entity_name;
@@ -19,6 +19,10 @@ pub struct DropDownBox<
     filter_by_input: bool,
     select_on_focus: bool,
     desired_width: Option<f32>,
+    char_limit: usize,
+    multiline: bool,
+    code_editor: bool,
+    password: bool,
 }
 
 impl<'a, F: FnMut(&mut Ui, &str) -> Response, V: AsRef<str>, I: Iterator<Item = V>>
@@ -40,6 +44,10 @@ impl<'a, F: FnMut(&mut Ui, &str) -> Response, V: AsRef<str>, I: Iterator<Item =
             filter_by_input: true,
             select_on_focus: false,
             desired_width: None,
+            char_limit: usize::MAX,
+            multiline: false,
+            code_editor: false,
+            password: false,
         }
     }
 
@@ -66,6 +74,30 @@ impl<'a, F: FnMut(&mut Ui, &str) -> Response, V: AsRef<str>, I: Iterator<Item =
         self.desired_width = desired_width.into();
         self
     }
+
+    /// Passes through the maximum number of characters to the underlying Text Edit
+    pub fn char_limit(mut self, char_limit: usize) -> Self {
+        self.char_limit = char_limit;
+        self
+    }
+
+    /// Use a multiline Text Edit
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Use a monospace font in the Text Edit, implies [`Self::multiline`]
+    pub fn code_editor(mut self, code_editor: bool) -> Self {
+        self.code_editor = code_editor;
+        self
+    }
+
+    /// Passes through whether to show the characters as dots to the underlying Text Edit
+    pub fn password(mut self, password: bool) -> Self {
+        self.password = password;
+        self
+    }
 }
 
 impl<'a, F: FnMut(&mut Ui, &str) -> Response, V: AsRef<str>, I: Iterator<Item = V>> Widget
@@ -81,9 +113,23 @@ impl<'a, F: FnMut(&mut Ui, &str) -> Response, V: AsRef<str>, I: Iterator<Item =
             filter_by_input,
             select_on_focus,
             desired_width,
+            char_limit,
+            multiline,
+            code_editor,
+            password,
         } = self;
 
-        let mut edit = TextEdit::singleline(buf).hint_text(hint_text);
+        let mut edit = if code_editor {
+            TextEdit::multiline(buf).code_editor()
+        } else if multiline {
+            TextEdit::multiline(buf)
+        } else {
+            TextEdit::singleline(buf)
+        };
+        edit = edit
+            .hint_text(hint_text)
+            .char_limit(char_limit)
+            .password(password);
         if let Some(dw) = desired_width {
             edit = edit.desired_width(dw);
         }
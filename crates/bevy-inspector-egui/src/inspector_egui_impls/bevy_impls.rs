@@ -1,8 +1,8 @@
-use bevy_color::{Color, Hsla, Hsva, Lcha, LinearRgba, Srgba};
+use bevy_color::{Color, Hsva, Hue, LinearRgba, Oklcha, Srgba};
 use bevy_ecs::entity::Entity;
 use bevy_ecs::world::CommandQueue;
 use bevy_ecs::world::World;
-use egui::Color32;
+use egui::color_picker::{self, Alpha};
 use std::any::Any;
 
 #[cfg(feature = "bevy_render")]
@@ -13,12 +13,16 @@ use ::{
 
 #[cfg(feature = "bevy_render")]
 use crate::bevy_inspector::errors::{dead_asset_handle, show_error};
+#[cfg(feature = "bevy_render")]
+use crate::reflect_inspector::ProjectorReflect;
 use crate::{
     bevy_inspector::errors::no_world_in_context,
     egui_utils,
-    inspector_options::std_options::{EntityDisplay, EntityOptions},
+    inspector_options::std_options::{ColorOptions, ColorSpace, EntityDisplay, EntityOptions},
     reflect_inspector::{Context, InspectorUi},
 };
+#[cfg(feature = "bevy_render")]
+use bevy_reflect::PartialReflect;
 
 use super::InspectorPrimitive;
 
@@ -157,6 +161,71 @@ impl InspectorPrimitive for Handle<Mesh> {
     }
 }
 
+/// Many-variant for `Handle<Mesh>`, applying the mesh operations to all distinct meshes
+#[cfg(feature = "bevy_render")]
+pub(super) fn mesh_ui_many(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> bool {
+    let mut handles: Vec<Handle<Mesh>> = Vec::new();
+    for value in values.iter_mut() {
+        let handle = projector(*value)
+            .try_downcast_ref::<Handle<Mesh>>()
+            .expect("non-fully-reflected value passed to mesh_ui_many");
+        if !handles.contains(handle) {
+            handles.push(handle.clone());
+        }
+    }
+    if let [handle] = handles.as_mut_slice() {
+        return handle.ui(ui, options, id, env);
+    }
+
+    let Some(world) = &mut env.context.world else {
+        no_world_in_context(ui, "Handle<Mesh>");
+        return false;
+    };
+    let mut meshes = match world.get_resource_mut::<Assets<Mesh>>() {
+        Ok(meshes) => meshes,
+        Err(error) => {
+            show_error(error, ui, "Assets<Mesh>");
+            return false;
+        }
+    };
+
+    ui.label(format!("{} different meshes", handles.len()));
+    if ui.button("Duplicate vertices").clicked() {
+        for handle in &handles {
+            if let Some(mesh) = meshes.get_mut(handle)
+                && mesh.indices().is_some()
+            {
+                mesh.duplicate_vertices();
+            }
+        }
+    }
+    if ui.button("Compute flat normals").clicked() {
+        for handle in &handles {
+            if let Some(mesh) = meshes.get_mut(handle)
+                && mesh.indices().is_none()
+            {
+                mesh.compute_flat_normals();
+            }
+        }
+    }
+    if ui.button("Generate tangents").clicked() {
+        for handle in &handles {
+            if let Some(mesh) = meshes.get_mut(handle) {
+                let _ = mesh.generate_tangents();
+            }
+        }
+    }
+
+    false
+}
+
 #[cfg(feature = "bevy_render")]
 fn mesh_ui_inner(mesh: &Mesh, ui: &mut egui::Ui) {
     egui::Grid::new("mesh").show(ui, |ui| {
@@ -202,103 +271,220 @@ fn mesh_ui_inner(mesh: &Mesh, ui: &mut egui::Ui) {
 }
 
 impl InspectorPrimitive for Color {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        match self {
-            Color::Srgba(Srgba {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<ColorOptions>()
+            .cloned()
+            .unwrap_or_default();
+
+        // the picker only edits components up to 1, so brighter colors are split into
+        // a base color and an intensity even without `hdr`
+        let linear = self.to_linear();
+        let intensity = linear.red.max(linear.green).max(linear.blue).max(1.0);
+        let base = scale_rgb(linear, 1.0 / intensity);
+        let mut new_intensity = intensity;
+
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            let alpha = if options.hide_alpha {
+                Alpha::Opaque
+            } else {
+                Alpha::OnlyBlend
+            };
+            let mut rgba =
+                egui::Rgba::from_rgba_unmultiplied(base.red, base.green, base.blue, base.alpha);
+            if color_picker::color_edit_button_rgba(ui, &mut rgba, alpha).changed() {
+                let [red, green, blue, alpha] = rgba.to_rgba_unmultiplied();
+                let linear = scale_rgb(LinearRgba::new(red, green, blue, alpha), intensity);
+                *self = convert_like(Color::LinearRgba(linear), *self);
+                changed = true;
+            }
+
+            if let Some(space) = options.space {
+                changed |= color_components_ui(ui, self, space, options.hdr, options.hide_alpha);
+            }
+
+            if (options.hdr || intensity > 1.0)
+                && ui
+                    .add(
+                        egui::DragValue::new(&mut new_intensity)
+                            .range(1.0..=f32::MAX)
+                            .speed(0.01)
+                            .prefix("×"),
+                    )
+                    .on_hover_text("Intensity")
+                    .changed()
+            {
+                let linear = scale_rgb(self.to_linear(), new_intensity / intensity);
+                *self = convert_like(Color::LinearRgba(linear), *self);
+                changed = true;
+            }
+        });
+
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = *self;
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
+
+fn scale_rgb(color: LinearRgba, factor: f32) -> LinearRgba {
+    LinearRgba {
+        red: color.red * factor,
+        green: color.green * factor,
+        blue: color.blue * factor,
+        alpha: color.alpha,
+    }
+}
+
+/// Edits the components of the color converted to `space`.
+///
+/// The result is stored in the color space `color` was in before.
+fn color_components_ui(
+    ui: &mut egui::Ui,
+    color: &mut Color,
+    space: ColorSpace,
+    hdr: bool,
+    hide_alpha: bool,
+) -> bool {
+    let (mut components, prefixes, max) = match space {
+        ColorSpace::Srgb => {
+            let Srgba {
                 red,
                 green,
                 blue,
                 alpha,
-            }) => {
-                let mut color = Color32::from_rgba_unmultiplied(
-                    (*red * 255.) as u8,
-                    (*green * 255.) as u8,
-                    (*blue * 255.) as u8,
-                    (*alpha * 255.) as u8,
-                );
-                if ui.color_edit_button_srgba(&mut color).changed() {
-                    let [r, g, b, a] = color.to_srgba_unmultiplied();
-                    *red = r as f32 / 255.;
-                    *green = g as f32 / 255.;
-                    *blue = b as f32 / 255.;
-                    *alpha = a as f32 / 255.;
-                    return true;
-                }
-            }
-            Color::LinearRgba(LinearRgba {
+            } = (*color).into();
+            ([red, green, blue, alpha], ["r ", "g ", "b "], [1.0; 3])
+        }
+        ColorSpace::Linear => {
+            let LinearRgba {
                 red,
                 green,
                 blue,
                 alpha,
-            }) => {
-                let mut color = [*red, *green, *blue, *alpha];
-                if ui
-                    .color_edit_button_rgba_premultiplied(&mut color)
-                    .changed()
-                {
-                    *red = color[0];
-                    *green = color[1];
-                    *blue = color[2];
-                    *alpha = color[3];
-                    return true;
-                }
-            }
-            Color::Hsla(Hsla {
+            } = (*color).into();
+            ([red, green, blue, alpha], ["r ", "g ", "b "], [1.0; 3])
+        }
+        ColorSpace::Hsv => {
+            let Hsva {
                 hue,
                 saturation,
-                lightness,
+                value,
                 alpha,
-            }) => {
-                let mut hsva = egui::ecolor::Hsva::new(*hue, *saturation, *lightness, *alpha);
-                if ui.color_edit_button_hsva(&mut hsva).changed() {
-                    *hue = hsva.h;
-                    *saturation = hsva.s;
-                    *lightness = hsva.v;
-                    *alpha = hsva.a;
-                    return true;
-                }
-            }
-            Color::Lcha(Lcha {
-                hue,
-                chroma,
+            } = (*color).into();
+            (
+                [hue, saturation, value, alpha],
+                ["h ", "s ", "v "],
+                [360.0, 1.0, 1.0],
+            )
+        }
+        ColorSpace::Oklch => {
+            let Oklcha {
                 lightness,
+                chroma,
+                hue,
                 alpha,
-            }) => {
-                let mut hsva = egui::ecolor::Hsva::new(*hue, *chroma, *lightness, *alpha);
-                if ui.color_edit_button_hsva(&mut hsva).changed() {
-                    *self = Color::Hsva(Hsva {
-                        hue: *hue,
-                        alpha: *alpha,
-                        saturation: *chroma,
-                        value: *lightness,
-                    });
-                    return true;
-                }
-            }
-            Color::Hsva(_)
-            | Color::Hwba(_)
-            | Color::Laba(_)
-            | Color::Oklaba(_)
-            | Color::Oklcha(_)
-            | Color::Xyza(_) => {
-                ui.label(format!(
-                    "Colorspace of {self:?} is not supported yet. PRs welcome"
-                ));
-                return false;
-            }
+            } = (*color).into();
+            (
+                [lightness, chroma, hue, alpha],
+                ["l ", "c ", "h "],
+                [1.0, 0.5, 360.0],
+            )
         }
-        false
+    };
+
+    let mut changed = false;
+    for ((component, prefix), max) in components.iter_mut().zip(prefixes).zip(max) {
+        // rgb components of hdr colors can go above 1
+        let hdr_component = hdr && matches!(space, ColorSpace::Srgb | ColorSpace::Linear);
+        changed |= ui
+            .add(
+                egui::DragValue::new(component)
+                    .range(0.0..=if hdr_component { f32::MAX } else { max })
+                    .clamp_existing_to_range(false)
+                    .speed(max / 100.0)
+                    .max_decimals(3)
+                    .prefix(prefix),
+            )
+            .changed();
+    }
+    if !hide_alpha {
+        changed |= ui
+            .add(
+                egui::DragValue::new(&mut components[3])
+                    .range(0.0..=1.0)
+                    .clamp_existing_to_range(false)
+                    .speed(0.01)
+                    .max_decimals(3)
+                    .prefix("a "),
+            )
+            .changed();
     }
 
-    fn ui_readonly(
-        &self,
-        ui: &mut egui::Ui,
-        options: &dyn Any,
-        id: egui::Id,
-        env: InspectorUi<'_, '_>,
-    ) {
-        let mut copy = *self;
-        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    if changed {
+        let [a, b, c, alpha] = components;
+        let new_color = match space {
+            ColorSpace::Srgb => Color::srgba(a, b, c, alpha),
+            ColorSpace::Linear => Color::linear_rgba(a, b, c, alpha),
+            ColorSpace::Hsv => Color::hsva(a, b, c, alpha),
+            ColorSpace::Oklch => Color::oklcha(a, b, c, alpha),
+        };
+        *color = convert_like(new_color, *color);
+    }
+    changed
+}
+
+/// Converts `color` into the color space of `old`, keeping the hue of `old` if the
+/// converted color is grey and the conversion would reset its hue to 0
+fn convert_like(color: Color, old: Color) -> Color {
+    if std::mem::discriminant(&color) == std::mem::discriminant(&old) {
+        return color;
+    }
+
+    let converted = in_space_of(color, old);
+    let hue_based = matches!(
+        old,
+        Color::Hsla(_) | Color::Hsva(_) | Color::Hwba(_) | Color::Lcha(_) | Color::Oklcha(_)
+    );
+    let Srgba {
+        red, green, blue, ..
+    } = color.into();
+    let grey = red.max(green).max(blue) - red.min(green).min(blue) <= 1e-4;
+    if hue_based && grey {
+        converted.with_hue(old.hue())
+    } else {
+        converted
+    }
+}
+
+/// Converts `color` into the color space `like` is stored in
+fn in_space_of(color: Color, like: Color) -> Color {
+    match like {
+        Color::Srgba(_) => Color::Srgba(color.into()),
+        Color::LinearRgba(_) => Color::LinearRgba(color.into()),
+        Color::Hsla(_) => Color::Hsla(color.into()),
+        Color::Hsva(_) => Color::Hsva(color.into()),
+        Color::Hwba(_) => Color::Hwba(color.into()),
+        Color::Laba(_) => Color::Laba(color.into()),
+        Color::Lcha(_) => Color::Lcha(color.into()),
+        Color::Oklaba(_) => Color::Oklaba(color.into()),
+        Color::Oklcha(_) => Color::Oklcha(color.into()),
+        Color::Xyza(_) => Color::Xyza(color.into()),
     }
 }
 
@@ -341,3 +527,33 @@ impl InspectorPrimitive for RenderLayers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_color::{Color, Hsla, Oklcha};
+
+    use super::convert_like;
+
+    #[test]
+    fn converting_grey_keeps_the_hue() {
+        let old = Color::Hsla(Hsla::new(120.0, 0.5, 0.5, 1.0));
+        let Color::Hsla(hsla) = convert_like(Color::srgb(0.3, 0.3, 0.3), old) else {
+            panic!("not converted to hsla");
+        };
+        assert_eq!(hsla.hue, 120.0);
+        assert!(hsla.saturation.abs() < 1e-4);
+
+        let old = Color::Oklcha(Oklcha::new(0.5, 0.1, 200.0, 1.0));
+        let Color::Oklcha(oklcha) = convert_like(Color::linear_rgb(0.2, 0.2, 0.2), old) else {
+            panic!("not converted to oklcha");
+        };
+        assert_eq!(oklcha.hue, 200.0);
+    }
+
+    #[test]
+    fn same_space_is_not_converted() {
+        let old = Color::Hsla(Hsla::new(120.0, 0.5, 0.5, 1.0));
+        let new = Color::Hsla(Hsla::new(40.0, 0.0, 0.5, 1.0));
+        assert_eq!(convert_like(new, old), new);
+    }
+}
@@ -0,0 +1,289 @@
+//! Arithmetic expressions typed into number fields, like `2*pi`, `1/60`, `+=0.5` or `*2`.
+
+/// A number typed into a number field, either absolute or relative to the current value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum NumberExpression {
+    /// `2*pi`
+    Absolute(f64),
+    /// `+=0.5` or `-=0.5`
+    Add(f64),
+    /// `*2`, `*=2`, `/2` or `/=2`
+    Mul(f64),
+}
+
+impl NumberExpression {
+    pub(crate) fn parse(text: &str) -> Option<NumberExpression> {
+        let text = text.trim();
+
+        let expression = if let Some(rest) = text.strip_prefix("+=") {
+            NumberExpression::Add(eval(rest)?)
+        } else if let Some(rest) = text.strip_prefix("-=") {
+            NumberExpression::Add(-eval(rest)?)
+        } else if let Some(rest) = text.strip_prefix("*=").or_else(|| text.strip_prefix('*')) {
+            NumberExpression::Mul(eval(rest)?)
+        } else if let Some(rest) = text.strip_prefix("/=").or_else(|| text.strip_prefix('/')) {
+            NumberExpression::Mul(1.0 / eval(rest)?)
+        } else {
+            NumberExpression::Absolute(eval(text)?)
+        };
+
+        match expression {
+            NumberExpression::Absolute(value)
+            | NumberExpression::Add(value)
+            | NumberExpression::Mul(value) => value.is_finite().then_some(expression),
+        }
+    }
+
+    pub(crate) fn apply(self, current: f64) -> f64 {
+        match self {
+            NumberExpression::Absolute(value) => value,
+            NumberExpression::Add(value) => current + value,
+            NumberExpression::Mul(value) => current * value,
+        }
+    }
+}
+
+/// Evaluates an expression consisting of numbers, `+ - * / % ^`, parentheses,
+/// the constants `pi`, `tau` and `e` and the functions `sqrt`, `abs`, `sin`, `cos`, `tan`, `ln`, `exp`, `deg` and `rad`.
+///
+/// A number directly followed by a constant, function or parenthesis is multiplied with it, so `2pi` is `2*pi`.
+pub(crate) fn eval(text: &str) -> Option<f64> {
+    let mut parser = Parser { text, pos: 0 };
+    let value = parser.expr()?;
+    parser.skip_whitespace();
+    (parser.pos == text.len() && value.is_finite()).then_some(value)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.text[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expr(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat('+') {
+                value += self.term()?;
+            } else if self.eat('-') {
+                value -= self.term()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat('*') {
+                value *= self.unary()?;
+            } else if self.eat('/') {
+                value /= self.unary()?;
+            } else if self.eat('%') {
+                value %= self.unary()?;
+            } else if self
+                .peek()
+                .is_some_and(|c| c == '(' || c.is_ascii_alphabetic())
+            {
+                value *= self.power()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Option<f64> {
+        if self.eat('-') {
+            Some(-self.unary()?)
+        } else if self.eat('+') {
+            self.unary()
+        } else {
+            self.power()
+        }
+    }
+
+    fn power(&mut self) -> Option<f64> {
+        let base = self.atom()?;
+        if self.eat('^') {
+            Some(base.powf(self.unary()?))
+        } else {
+            Some(base)
+        }
+    }
+
+    fn atom(&mut self) -> Option<f64> {
+        if self.eat('(') {
+            let value = self.expr()?;
+            return self.eat(')').then_some(value);
+        }
+
+        let c = self.peek()?;
+        if c.is_ascii_digit() || c == '.' {
+            return self.number();
+        }
+
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        let ident = &rest[..len];
+        self.pos += len;
+
+        let function: fn(f64) -> f64 = match ident {
+            "pi" => return Some(std::f64::consts::PI),
+            "tau" => return Some(std::f64::consts::TAU),
+            "e" => return Some(std::f64::consts::E),
+            "sqrt" => f64::sqrt,
+            "abs" => f64::abs,
+            "sin" => f64::sin,
+            "cos" => f64::cos,
+            "tan" => f64::tan,
+            "ln" => f64::ln,
+            "exp" => f64::exp,
+            "deg" => f64::to_degrees,
+            "rad" => f64::to_radians,
+            _ => return None,
+        };
+        if !self.eat('(') {
+            return None;
+        }
+        let argument = self.expr()?;
+        self.eat(')').then(|| function(argument))
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        let rest = &self.text[self.pos..];
+        let mut len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+
+        // exponent, but not the constant `e` as in `2e`
+        let exponent = &rest[len..];
+        if let Some(after_e) = exponent.strip_prefix(['e', 'E']) {
+            let sign_len = usize::from(after_e.starts_with(['+', '-']));
+            let digits = after_e[sign_len..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(after_e.len() - sign_len);
+            if digits > 0 {
+                len += 1 + sign_len + digits;
+            }
+        }
+
+        let value = rest[..len].parse().ok()?;
+        self.pos += len;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::emath::Numeric;
+
+    use super::{NumberExpression, eval};
+
+    #[test]
+    fn precedence() {
+        assert_eq!(eval("1 + 2 * 3"), Some(7.0));
+        assert_eq!(eval("(1 + 2) * 3"), Some(9.0));
+        assert_eq!(eval("10 - 4 - 3"), Some(3.0));
+        assert_eq!(eval("2 * 3 ^ 2"), Some(18.0));
+        assert_eq!(eval("2 ^ 3 ^ 2"), Some(512.0));
+        assert_eq!(eval("7 % 4 * 2"), Some(6.0));
+    }
+
+    #[test]
+    fn unary_minus() {
+        assert_eq!(eval("-2"), Some(-2.0));
+        assert_eq!(eval("--2"), Some(2.0));
+        assert_eq!(eval("2 * -3"), Some(-6.0));
+        assert_eq!(eval("-2 ^ 2"), Some(-4.0));
+        assert_eq!(eval("2 ^ -1"), Some(0.5));
+    }
+
+    #[test]
+    fn implicit_multiplication() {
+        assert_eq!(eval("2pi"), Some(std::f64::consts::TAU));
+        assert_eq!(eval("2(3 + 1)"), Some(8.0));
+        assert_eq!(eval("2e"), Some(2.0 * std::f64::consts::E));
+        assert_eq!(eval("2e3"), Some(2000.0));
+        assert_eq!(eval("3sqrt(4)"), Some(6.0));
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        assert_eq!(eval("1/0"), None);
+        assert_eq!(eval("0/0"), None);
+        assert_eq!(eval("(1 + 2"), None);
+        assert_eq!(eval("1 + 2)"), None);
+        assert_eq!(eval("sqrt 4"), None);
+        assert_eq!(eval("foo"), None);
+        assert_eq!(eval("2 * bar"), None);
+        assert_eq!(eval(""), None);
+        assert_eq!(NumberExpression::parse("/0"), None);
+    }
+
+    #[test]
+    fn relative_expressions() {
+        assert_eq!(
+            NumberExpression::parse("+=0.5"),
+            Some(NumberExpression::Add(0.5))
+        );
+        assert_eq!(
+            NumberExpression::parse("-= 2"),
+            Some(NumberExpression::Add(-2.0))
+        );
+        assert_eq!(
+            NumberExpression::parse("*2"),
+            Some(NumberExpression::Mul(2.0))
+        );
+        assert_eq!(
+            NumberExpression::parse("/=4"),
+            Some(NumberExpression::Mul(0.25))
+        );
+        assert_eq!(
+            NumberExpression::parse("-2"),
+            Some(NumberExpression::Absolute(-2.0))
+        );
+        assert_eq!(
+            NumberExpression::parse("1/60").map(|e| e.apply(5.0)),
+            Some(1.0 / 60.0)
+        );
+    }
+
+    #[test]
+    fn relative_expressions_on_integers() {
+        fn apply<T: Numeric>(text: &str, current: T) -> T {
+            let expression = NumberExpression::parse(text).unwrap();
+            T::from_f64(expression.apply(current.to_f64()))
+        }
+
+        assert_eq!(apply("+=3", 5i32), 8);
+        assert_eq!(apply("-=10", 5i32), -5);
+        assert_eq!(apply("*2", 21u64), 42);
+        assert_eq!(apply("/3", 10u8), 3);
+        assert_eq!(apply("*0.5", 7usize), 3);
+        // results outside of the type's range saturate
+        assert_eq!(apply("-=10", 5u8), 0);
+        assert_eq!(apply("*100", 100i8), i8::MAX);
+    }
+}
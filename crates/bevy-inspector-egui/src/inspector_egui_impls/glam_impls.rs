@@ -1,8 +1,12 @@
 use std::any::Any;
 
-use bevy_math::{DMat2, DMat3, DMat4, DVec2, DVec3, DVec4, Mat3A, Vec3A, prelude::*};
+use bevy_math::{
+    Affine2, Affine3A, DMat2, DMat3, DMat4, DVec2, DVec3, DVec4, Mat3A, Vec3A, prelude::*,
+};
 use bevy_reflect::PartialReflect;
+use egui::emath::Numeric;
 
+use super::std_impls::{apply_expression, from_base_unit};
 use crate::inspector_options::std_options::NumberOptions;
 use crate::reflect_inspector::InspectorUi;
 use crate::reflect_inspector::ProjectorReflect;
@@ -11,12 +15,17 @@ macro_rules! vec_ui_many {
     ($name_many:ident $ty:ty>$elem_ty:ty: $count:literal $($component:ident)*) => {
         pub fn $name_many(
             ui: &mut egui::Ui,
-            _: &dyn Any,
+            options: &dyn Any,
             id: egui::Id,
             _env: InspectorUi<'_, '_>,
             values: &mut [&mut dyn PartialReflect],
             projector: &dyn ProjectorReflect,
         ) -> bool {
+            let options = options
+                .downcast_ref::<NumberOptions<$ty>>()
+                .cloned()
+                .unwrap_or_default();
+
             let mut changed = false;
             ui.scope(|ui| {
                 ui.style_mut().spacing.item_spacing = egui::Vec2::new(4.0, 0.);
@@ -31,18 +40,15 @@ macro_rules! vec_ui_many {
                             }));
 
                             let id = id.with(stringify!($component));
-                            changed |= crate::inspector_egui_impls::change_slider($component, id, same, |change, overwrite| {
+                            let component_options = options.map(|vec| vec.$component);
+                            let same = same.map(|same| from_base_unit(same.to_f64(), &component_options));
+                            changed |= crate::inspector_egui_impls::change_slider($component, id, same, |expression| {
                                 for value in values.iter_mut() {
                                     let value = projector(*value);
                                     let value = value.try_downcast_mut::<$ty>().unwrap();
 
-                                    if false { value.$component = change };
-                                    if overwrite {
-                                        value.$component = change;
-                                    } else {
-                                        value.$component += change;
-                                    }
-
+                                    let new_value = apply_expression(expression, value.$component.to_f64(), &component_options);
+                                    value.$component = <$elem_ty>::from_f64(new_value);
                                 }
                             });
                         )*
@@ -142,6 +148,72 @@ macro_rules! mat_ui {
     };
 }
 
+macro_rules! bvec_ui_many {
+    ($name_many:ident $ty:ty: $count:literal $($component:ident)*) => {
+        pub fn $name_many(
+            ui: &mut egui::Ui,
+            _: &dyn Any,
+            _: egui::Id,
+            _env: InspectorUi<'_, '_>,
+            values: &mut [&mut dyn PartialReflect],
+            projector: &dyn ProjectorReflect,
+        ) -> bool {
+            let mut changed = false;
+            ui.scope(|ui| {
+                ui.style_mut().spacing.item_spacing = egui::Vec2::new(4.0, 0.);
+
+                ui.columns($count, |ui| match ui {
+                    [$($component),*] => {
+                        $(
+                            let same = super::iter_all_eq(values.iter_mut().map(|value| {
+                                projector(*value).try_downcast_ref::<$ty>().unwrap().$component
+                            }));
+
+                            let mut checked = same.unwrap_or_default();
+                            let checkbox = egui::Checkbox::without_text(&mut checked).indeterminate(same.is_none());
+                            if $component.add(checkbox).changed() {
+                                for value in values.iter_mut() {
+                                    projector(*value).try_downcast_mut::<$ty>().unwrap().$component = checked;
+                                }
+                                changed = true;
+                            }
+                        )*
+                    }
+                    _ => unreachable!(),
+                });
+            });
+            changed
+        }
+    };
+}
+
+macro_rules! mat_ui_many {
+    ($name_many:ident $ty:ty>$column_ty:ty: $($component:ident)*) => {
+        pub fn $name_many(
+            ui: &mut egui::Ui,
+            _: &dyn Any,
+            id: egui::Id,
+            mut env: InspectorUi<'_, '_>,
+            values: &mut [&mut dyn PartialReflect],
+            projector: &dyn ProjectorReflect,
+        ) -> bool {
+            let mut changed = false;
+            ui.vertical(|ui| {
+                $(changed |= env.ui_for_reflect_many_with_options(
+                    std::any::TypeId::of::<$column_ty>(),
+                    stringify!($column_ty),
+                    ui,
+                    id.with(stringify!($component)),
+                    &(),
+                    values,
+                    &|value| &mut projector(value).try_downcast_mut::<$ty>().unwrap().$component,
+                );)*
+            });
+            changed
+        }
+    };
+}
+
 vec_ui!(vec2_ui vec2_ui_readonly Vec2: 2 x y);
 vec_ui!(vec3_ui vec3_ui_readonly Vec3: 3 x y z);
 vec_ui!(vec3a_ui vec3a_ui_readonly Vec3A: 3 x y z);
@@ -179,48 +251,148 @@ mat_ui!(mat4_ui mat4_ui_readonly Mat4: x_axis y_axis z_axis w_axis);
 mat_ui!(dmat2_ui dmat2_ui_readonly DMat2: x_axis y_axis);
 mat_ui!(dmat3_ui dmat3_ui_readonly DMat3: x_axis y_axis z_axis);
 mat_ui!(dmat4_ui dmat4_ui_readonly DMat4: x_axis y_axis z_axis w_axis);
+mat_ui!(affine2_ui affine2_ui_readonly Affine2: matrix2 translation);
+mat_ui!(affine3a_ui affine3a_ui_readonly Affine3A: matrix3 translation);
+bvec_ui_many!(bvec2_ui_many BVec2: 2 x y);
+bvec_ui_many!(bvec3_ui_many BVec3: 3 x y z);
+bvec_ui_many!(bvec4_ui_many BVec4: 4 x y z w);
+mat_ui_many!(mat2_ui_many Mat2>Vec2: x_axis y_axis);
+mat_ui_many!(mat3_ui_many Mat3>Vec3: x_axis y_axis z_axis);
+mat_ui_many!(mat3a_ui_many Mat3A>Vec3A: x_axis y_axis z_axis);
+mat_ui_many!(mat4_ui_many Mat4>Vec4: x_axis y_axis z_axis w_axis);
+mat_ui_many!(dmat2_ui_many DMat2>DVec2: x_axis y_axis);
+mat_ui_many!(dmat3_ui_many DMat3>DVec3: x_axis y_axis z_axis);
+mat_ui_many!(dmat4_ui_many DMat4>DVec4: x_axis y_axis z_axis w_axis);
 
 pub mod quat {
     use std::any::Any;
 
     use bevy_math::prelude::*;
+    use egui::{Sense, Stroke, vec2};
 
     use crate::{
-        inspector_options::std_options::{QuatDisplay, QuatOptions},
+        inspector_options::std_options::{Angle, NumberOptions, QuatDisplay, QuatOptions, Unit},
         many_ui,
         reflect_inspector::InspectorUi,
     };
 
     #[derive(Clone, Copy)]
-    struct Euler(Vec3);
+    struct Euler {
+        angles: [f32; 3],
+        order: EulerRot,
+    }
     #[derive(Clone, Copy)]
     struct YawPitchRoll((f32, f32, f32));
     #[derive(Clone, Copy)]
     struct AxisAngle((Vec3, f32));
 
     trait RotationEdit {
-        fn from_quat(quat: Quat) -> Self;
+        fn from_quat(quat: Quat, options: &QuatOptions) -> Self;
         fn to_quat(self) -> Quat;
 
-        fn ui(&mut self, ui: &mut egui::Ui, env: InspectorUi<'_, '_>) -> bool;
+        /// Whether the stored intermediate value was created with different options and needs to be recreated
+        fn options_changed(&self, _options: &QuatOptions) -> bool {
+            false
+        }
+
+        fn ui(
+            &mut self,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            env: InspectorUi<'_, '_>,
+        ) -> bool;
+    }
+
+    /// Edits an angle stored in radians in the [`QuatOptions::unit`], or `default_unit` if none is set
+    pub(super) fn angle_ui(
+        angle: &mut f32,
+        ui: &mut egui::Ui,
+        unit: Option<Angle>,
+        default_unit: Angle,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let unit = unit.unwrap_or(default_unit);
+        let speed = match unit {
+            Angle::Radians => 0.01,
+            Angle::Degrees => 1.0,
+            Angle::Turns => 0.002,
+        };
+        let options = NumberOptions::<f32> {
+            unit: Some(Unit::Angle(unit)),
+            ..Default::default()
+        }
+        .with_speed(speed);
+        env.ui_for_reflect_with_options(angle, ui, id, &options)
+    }
+
+    /// The axes of an [`EulerRot`] in the order they are applied, like `["Z", "Y", "X"]` for [`EulerRot::ZYX`]
+    fn euler_axes(order: EulerRot) -> [&'static str; 3] {
+        let name = format!("{order:?}");
+        let axis = |i: usize| match name.as_bytes()[i] {
+            b'X' => "X",
+            b'Y' => "Y",
+            _ => "Z",
+        };
+        [axis(0), axis(1), axis(2)]
     }
 
     impl RotationEdit for Euler {
-        fn from_quat(quat: Quat) -> Self {
-            Euler(quat.to_euler(EulerRot::XYZ).into())
+        fn from_quat(quat: Quat, options: &QuatOptions) -> Self {
+            let (a, b, c) = quat.to_euler(options.euler_rot);
+            Euler {
+                angles: [a, b, c],
+                order: options.euler_rot,
+            }
         }
 
         fn to_quat(self) -> Quat {
-            Quat::from_euler(EulerRot::XYZ, self.0.x, self.0.y, self.0.z)
+            let [a, b, c] = self.angles;
+            Quat::from_euler(self.order, a, b, c)
         }
 
-        fn ui(&mut self, ui: &mut egui::Ui, mut env: InspectorUi<'_, '_>) -> bool {
-            env.ui_for_reflect(&mut self.0, ui)
+        fn options_changed(&self, options: &QuatOptions) -> bool {
+            self.order != options.euler_rot
+        }
+
+        fn ui(
+            &mut self,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            mut env: InspectorUi<'_, '_>,
+        ) -> bool {
+            let axes = euler_axes(self.order);
+
+            let mut changed = false;
+            ui.scope(|ui| {
+                ui.style_mut().spacing.item_spacing = egui::Vec2::new(4.0, 0.);
+
+                ui.columns(3, |columns| {
+                    for (i, column) in columns.iter_mut().enumerate() {
+                        column.horizontal(|ui| {
+                            ui.label(axes[i]);
+                            changed |= angle_ui(
+                                &mut self.angles[i],
+                                ui,
+                                options.unit,
+                                Angle::Radians,
+                                id.with(i),
+                                env.reborrow(),
+                            );
+                        });
+                    }
+                });
+            })
+            .response
+            .on_hover_text(format!("{:?} euler angles", self.order));
+            changed
         }
     }
 
     impl RotationEdit for YawPitchRoll {
-        fn from_quat(quat: Quat) -> Self {
+        fn from_quat(quat: Quat, _: &QuatOptions) -> Self {
             YawPitchRoll(quat.to_euler(EulerRot::YXZ))
         }
 
@@ -229,21 +401,30 @@ pub mod quat {
             Quat::from_euler(EulerRot::YXZ, y, p, r)
         }
 
-        fn ui(&mut self, ui: &mut egui::Ui, _env: InspectorUi<'_, '_>) -> bool {
+        fn ui(
+            &mut self,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            mut env: InspectorUi<'_, '_>,
+        ) -> bool {
             let (yaw, pitch, roll) = &mut self.0;
 
             let mut changed = false;
             ui.vertical(|ui| {
                 egui::Grid::new("ypr grid").show(ui, |ui| {
-                    ui.label("Yaw");
-                    changed |= ui.drag_angle(yaw).changed();
-                    ui.end_row();
-                    ui.label("Pitch").changed();
-                    changed |= ui.drag_angle(pitch).changed();
-                    ui.end_row();
-                    ui.label("Roll");
-                    changed |= ui.drag_angle(roll).changed();
-                    ui.end_row();
+                    for (label, angle) in [("Yaw", yaw), ("Pitch", pitch), ("Roll", roll)] {
+                        ui.label(label);
+                        changed |= angle_ui(
+                            angle,
+                            ui,
+                            options.unit,
+                            Angle::Degrees,
+                            id.with(label),
+                            env.reborrow(),
+                        );
+                        ui.end_row();
+                    }
                 });
             });
             changed
@@ -251,7 +432,7 @@ pub mod quat {
     }
 
     impl RotationEdit for AxisAngle {
-        fn from_quat(quat: Quat) -> Self {
+        fn from_quat(quat: Quat, _: &QuatOptions) -> Self {
             AxisAngle(quat.to_axis_angle())
         }
 
@@ -265,7 +446,13 @@ pub mod quat {
             }
         }
 
-        fn ui(&mut self, ui: &mut egui::Ui, mut env: InspectorUi<'_, '_>) -> bool {
+        fn ui(
+            &mut self,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            mut env: InspectorUi<'_, '_>,
+        ) -> bool {
             let (axis, angle) = &mut self.0;
 
             let mut changed = false;
@@ -275,7 +462,14 @@ pub mod quat {
                     changed |= env.ui_for_reflect(axis, ui);
                     ui.end_row();
                     ui.label("Angle");
-                    changed |= ui.drag_angle(angle).changed();
+                    changed |= angle_ui(
+                        angle,
+                        ui,
+                        options.unit,
+                        Angle::Degrees,
+                        id.with("angle"),
+                        env.reborrow(),
+                    );
                     ui.end_row();
                 });
             });
@@ -286,35 +480,99 @@ pub mod quat {
     fn quat_ui_kind<T: Send + Sync + 'static + Copy + RotationEdit>(
         val: &mut Quat,
         ui: &mut egui::Ui,
+        options: &QuatOptions,
+        id: egui::Id,
         env: InspectorUi<'_, '_>,
     ) -> bool {
-        let id = ui.id();
+        let memory_id = ui.id();
         let mut intermediate = ui.memory_mut(|memory| {
             *memory
                 .data
-                .get_temp_mut_or_insert_with(id, || T::from_quat(*val))
+                .get_temp_mut_or_insert_with(memory_id, || T::from_quat(*val, options))
         });
 
-        let externally_changed = !intermediate.to_quat().abs_diff_eq(*val, f32::EPSILON);
+        let externally_changed = !intermediate.to_quat().abs_diff_eq(*val, f32::EPSILON)
+            || intermediate.options_changed(options);
         if externally_changed {
-            intermediate = T::from_quat(*val);
+            intermediate = T::from_quat(*val, options);
         }
 
-        let changed = intermediate.ui(ui, env);
+        let changed = intermediate.ui(ui, options, id, env);
 
         if changed || externally_changed {
             *val = intermediate.to_quat();
-            ui.memory_mut(|memory| memory.data.insert_temp(id, intermediate));
+            ui.memory_mut(|memory| memory.data.insert_temp(memory_id, intermediate));
         }
 
         changed
     }
 
+    /// Maps a position in the arcball to a point on the unit sphere facing the viewer, with `+y` up
+    fn arcball_point(pos: egui::Pos2, center: egui::Pos2, radius: f32) -> Vec3 {
+        let point = Vec2::new(pos.x - center.x, center.y - pos.y) / radius;
+        let length_squared = point.length_squared();
+        if length_squared <= 1.0 {
+            point.extend((1.0 - length_squared).sqrt())
+        } else {
+            point.normalize().extend(0.0)
+        }
+    }
+
+    /// Sphere showing the rotated axes, which is dragged to rotate in view space. Double clicking resets the rotation.
+    fn arcball_ui(val: &mut Quat, ui: &mut egui::Ui) -> bool {
+        let size = ui.spacing().interact_size.y * 5.0;
+        let (rect, response) = ui.allocate_exact_size(vec2(size, size), Sense::click_and_drag());
+        let center = rect.center();
+        let radius = size / 2.0 - 2.0;
+
+        let visuals = ui.style().interact(&response);
+        let painter = ui.painter_at(rect);
+        painter.circle(center, radius, visuals.bg_fill, visuals.bg_stroke);
+
+        let mut axes = [
+            (Vec3::X, egui::Color32::from_rgb(230, 70, 70)),
+            (Vec3::Y, egui::Color32::from_rgb(90, 200, 90)),
+            (Vec3::Z, egui::Color32::from_rgb(80, 130, 240)),
+        ]
+        .map(|(axis, color)| (*val * axis, color));
+        // paint the axes pointing away from the viewer first
+        axes.sort_by(|(a, _), (b, _)| a.z.total_cmp(&b.z));
+        for (axis, color) in axes {
+            let end = center + vec2(axis.x, -axis.y) * radius * 0.9;
+            let color = if axis.z < 0.0 {
+                color.gamma_multiply(0.4)
+            } else {
+                color
+            };
+            painter.line_segment([center, end], Stroke::new(2.0, color));
+            painter.circle_filled(end, 3.0, color);
+        }
+
+        let response = response.on_hover_text("Drag to rotate, double click to reset");
+
+        if response.double_clicked() {
+            *val = Quat::IDENTITY;
+            return true;
+        }
+
+        if response.dragged_by(egui::PointerButton::Primary)
+            && response.drag_delta() != egui::Vec2::ZERO
+            && let Some(pos) = response.interact_pointer_pos()
+        {
+            let from = arcball_point(pos - response.drag_delta(), center, radius);
+            let to = arcball_point(pos, center, radius);
+            *val = (Quat::from_rotation_arc(from, to) * *val).normalize();
+            return true;
+        }
+
+        false
+    }
+
     pub fn quat_ui(
         value: &mut dyn Any,
         ui: &mut egui::Ui,
         options: &dyn Any,
-        _: egui::Id,
+        id: egui::Id,
         mut env: InspectorUi<'_, '_>,
     ) -> bool {
         let value = value.downcast_mut::<Quat>().unwrap();
@@ -333,9 +591,10 @@ pub mod quat {
                 }
                 changed
             }
-            QuatDisplay::Euler => quat_ui_kind::<Euler>(value, ui, env),
-            QuatDisplay::YawPitchRoll => quat_ui_kind::<YawPitchRoll>(value, ui, env),
-            QuatDisplay::AxisAngle => quat_ui_kind::<AxisAngle>(value, ui, env),
+            QuatDisplay::Euler => quat_ui_kind::<Euler>(value, ui, &options, id, env),
+            QuatDisplay::YawPitchRoll => quat_ui_kind::<YawPitchRoll>(value, ui, &options, id, env),
+            QuatDisplay::AxisAngle => quat_ui_kind::<AxisAngle>(value, ui, &options, id, env),
+            QuatDisplay::Arcball => arcball_ui(value, ui),
         })
         .inner
     }
@@ -353,3 +612,419 @@ pub mod quat {
 
     many_ui!(quat_ui_many quat_ui Quat);
 }
+
+/// Matrices and transforms shown as translation, rotation and scale, see [`MatrixDisplay::Decomposed`](crate::inspector_options::std_options::MatrixDisplay::Decomposed)
+pub mod decomposed {
+    use std::any::Any;
+
+    use bevy_math::{Affine2, Affine3A, DMat3, DMat4, DQuat, DVec3, prelude::*};
+    use bevy_reflect::PartialReflect;
+    use bevy_transform::components::GlobalTransform;
+
+    use super::quat::angle_ui;
+    use crate::{
+        inspector_options::std_options::{Angle, MatrixDisplay, MatrixOptions, QuatOptions},
+        many_ui,
+        reflect_inspector::{InspectorUi, ProjectorReflect, errors},
+    };
+
+    trait Decompose: Copy + 'static {
+        type Vec: PartialReflect + Copy;
+        type Rotation: Copy;
+
+        /// Translation, rotation and scale, or `None` if the matrix is not invertible
+        fn decompose(self) -> Option<(Self::Vec, Self::Rotation, Self::Vec)>;
+        fn recompose(translation: Self::Vec, rotation: Self::Rotation, scale: Self::Vec) -> Self;
+        fn approx_eq(self, other: Self) -> bool;
+
+        fn rotation_ui(
+            rotation: &mut Self::Rotation,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            env: InspectorUi<'_, '_>,
+        ) -> bool;
+    }
+
+    /// Whether the linear part of a matrix is too close to singular to be decomposed.
+    ///
+    /// The determinant is compared relative to the volume spanned by the lengths of the axes,
+    /// so that uniformly small or large scales can still be decomposed.
+    fn is_singular<const N: usize>(determinant: f64, axis_lengths: [f64; N], epsilon: f64) -> bool {
+        let volume: f64 = axis_lengths.iter().product();
+        let ratio = determinant.abs() / volume;
+        !ratio.is_finite() || ratio <= epsilon
+    }
+
+    fn quat_rotation_ui(
+        rotation: &mut Quat,
+        ui: &mut egui::Ui,
+        options: &QuatOptions,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        env.ui_for_reflect_with_options(rotation, ui, id, options)
+    }
+
+    impl Decompose for Mat4 {
+        type Vec = Vec3;
+        type Rotation = Quat;
+
+        fn decompose(self) -> Option<(Vec3, Quat, Vec3)> {
+            let m = Mat3::from_mat4(self);
+            let axes = [m.x_axis, m.y_axis, m.z_axis].map(|axis| axis.length() as f64);
+            if is_singular(m.determinant() as f64, axes, f32::EPSILON as f64) {
+                return None;
+            }
+            let (scale, rotation, translation) = self.to_scale_rotation_translation();
+            Some((translation, rotation, scale))
+        }
+        fn recompose(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+            Mat4::from_scale_rotation_translation(scale, rotation, translation)
+        }
+        fn approx_eq(self, other: Self) -> bool {
+            self.abs_diff_eq(other, 1e-4)
+        }
+
+        fn rotation_ui(
+            rotation: &mut Quat,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            env: InspectorUi<'_, '_>,
+        ) -> bool {
+            quat_rotation_ui(rotation, ui, options, id, env)
+        }
+    }
+
+    impl Decompose for DMat4 {
+        type Vec = DVec3;
+        type Rotation = DQuat;
+
+        fn decompose(self) -> Option<(DVec3, DQuat, DVec3)> {
+            let m = DMat3::from_mat4(self);
+            let axes = [m.x_axis, m.y_axis, m.z_axis].map(|axis| axis.length());
+            if is_singular(m.determinant(), axes, f64::EPSILON) {
+                return None;
+            }
+            let (scale, rotation, translation) = self.to_scale_rotation_translation();
+            Some((translation, rotation, scale))
+        }
+        fn recompose(translation: DVec3, rotation: DQuat, scale: DVec3) -> Self {
+            DMat4::from_scale_rotation_translation(scale, rotation, translation)
+        }
+        fn approx_eq(self, other: Self) -> bool {
+            self.abs_diff_eq(other, 1e-8)
+        }
+
+        /// Edited with the [`Quat`] widget, losing precision only when the rotation is changed
+        fn rotation_ui(
+            rotation: &mut DQuat,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            env: InspectorUi<'_, '_>,
+        ) -> bool {
+            let mut quat = rotation.as_quat();
+            let changed = quat_rotation_ui(&mut quat, ui, options, id, env);
+            if changed {
+                *rotation = quat.as_dquat();
+            }
+            changed
+        }
+    }
+
+    impl Decompose for Affine3A {
+        type Vec = Vec3;
+        type Rotation = Quat;
+
+        fn decompose(self) -> Option<(Vec3, Quat, Vec3)> {
+            let m = self.matrix3;
+            let axes = [m.x_axis, m.y_axis, m.z_axis].map(|axis| axis.length() as f64);
+            if is_singular(m.determinant() as f64, axes, f32::EPSILON as f64) {
+                return None;
+            }
+            let (scale, rotation, translation) = self.to_scale_rotation_translation();
+            Some((translation, rotation, scale))
+        }
+        fn recompose(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+            Affine3A::from_scale_rotation_translation(scale, rotation, translation)
+        }
+        fn approx_eq(self, other: Self) -> bool {
+            self.abs_diff_eq(other, 1e-4)
+        }
+
+        fn rotation_ui(
+            rotation: &mut Quat,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            env: InspectorUi<'_, '_>,
+        ) -> bool {
+            quat_rotation_ui(rotation, ui, options, id, env)
+        }
+    }
+
+    impl Decompose for Affine2 {
+        type Vec = Vec2;
+        type Rotation = f32;
+
+        fn decompose(self) -> Option<(Vec2, f32, Vec2)> {
+            let m = self.matrix2;
+            let axes = [m.x_axis, m.y_axis].map(|axis| axis.length() as f64);
+            if is_singular(m.determinant() as f64, axes, f32::EPSILON as f64) {
+                return None;
+            }
+            let (scale, angle, translation) = self.to_scale_angle_translation();
+            Some((translation, angle, scale))
+        }
+        fn recompose(translation: Vec2, angle: f32, scale: Vec2) -> Self {
+            Affine2::from_scale_angle_translation(scale, angle, translation)
+        }
+        fn approx_eq(self, other: Self) -> bool {
+            self.abs_diff_eq(other, 1e-4)
+        }
+
+        fn rotation_ui(
+            angle: &mut f32,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            env: InspectorUi<'_, '_>,
+        ) -> bool {
+            angle_ui(angle, ui, options.unit, Angle::Degrees, id, env)
+        }
+    }
+
+    impl Decompose for GlobalTransform {
+        type Vec = Vec3;
+        type Rotation = Quat;
+
+        fn decompose(self) -> Option<(Vec3, Quat, Vec3)> {
+            self.affine().decompose()
+        }
+        fn recompose(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+            GlobalTransform::from(Affine3A::recompose(translation, rotation, scale))
+        }
+        fn approx_eq(self, other: Self) -> bool {
+            self.affine().approx_eq(other.affine())
+        }
+
+        fn rotation_ui(
+            rotation: &mut Quat,
+            ui: &mut egui::Ui,
+            options: &QuatOptions,
+            id: egui::Id,
+            env: InspectorUi<'_, '_>,
+        ) -> bool {
+            quat_rotation_ui(rotation, ui, options, id, env)
+        }
+    }
+
+    /// Returns `None` if the matrix is not invertible and can't be decomposed
+    fn decomposed_ui<M: Decompose>(
+        value: &mut M,
+        ui: &mut egui::Ui,
+        options: &MatrixOptions,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> Option<bool> {
+        let (mut translation, mut rotation, mut scale) = value.decompose()?;
+        let lossy = !M::recompose(translation, rotation, scale).approx_eq(*value);
+
+        let mut changed = false;
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            ui.label("Translation");
+            changed |=
+                env.ui_for_reflect_with_options(&mut translation, ui, id.with("translation"), &());
+            ui.end_row();
+            ui.label("Rotation");
+            changed |= M::rotation_ui(
+                &mut rotation,
+                ui,
+                &options.rotation,
+                id.with("rotation"),
+                env.reborrow(),
+            );
+            ui.end_row();
+            ui.label("Scale");
+            changed |= env.ui_for_reflect_with_options(&mut scale, ui, id.with("scale"), &());
+            ui.end_row();
+        });
+        if lossy {
+            errors::invalid_value(
+                ui,
+                "has shear or projection, which is removed when editing the decomposed values",
+            );
+        }
+
+        if changed {
+            *value = M::recompose(translation, rotation, scale);
+        }
+        Some(changed)
+    }
+
+    fn matrix_ui<M: Decompose>(
+        value: &mut dyn Any,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+        default_display: MatrixDisplay,
+        raw: fn(&mut M, &mut egui::Ui, egui::Id, InspectorUi<'_, '_>) -> bool,
+    ) -> bool {
+        let value = value.downcast_mut::<M>().unwrap();
+        let options = options
+            .downcast_ref::<MatrixOptions>()
+            .cloned()
+            .unwrap_or_default();
+
+        ui.vertical(|ui| {
+            if options.display.unwrap_or(default_display) == MatrixDisplay::Decomposed {
+                match decomposed_ui(value, ui, &options, id, env.reborrow()) {
+                    Some(changed) => return changed,
+                    None => errors::invalid_value(ui, "not invertible, so it can't be decomposed"),
+                }
+            }
+            raw(value, ui, id, env)
+        })
+        .inner
+    }
+
+    /// `$raw` and `$raw_readonly` show the matrix when it isn't decomposed
+    macro_rules! matrix_ui {
+        ($name:ident $name_readonly:ident $ty:ty, $default_display:expr, $raw:expr, $raw_readonly:expr) => {
+            pub fn $name(
+                value: &mut dyn Any,
+                ui: &mut egui::Ui,
+                options: &dyn Any,
+                id: egui::Id,
+                env: InspectorUi<'_, '_>,
+            ) -> bool {
+                matrix_ui::<$ty>(value, ui, options, id, env, $default_display, $raw)
+            }
+
+            pub fn $name_readonly(
+                value: &dyn Any,
+                ui: &mut egui::Ui,
+                options: &dyn Any,
+                id: egui::Id,
+                env: InspectorUi<'_, '_>,
+            ) {
+                let value = value.downcast_ref::<$ty>().unwrap();
+                let display = options
+                    .downcast_ref::<MatrixOptions>()
+                    .and_then(|options| options.display)
+                    .unwrap_or($default_display);
+
+                if display == MatrixDisplay::Decomposed {
+                    let mut value = *value;
+                    ui.add_enabled_ui(false, |ui| $name(&mut value, ui, options, id, env));
+                } else {
+                    let raw_readonly: fn(&$ty, &mut egui::Ui, egui::Id, InspectorUi<'_, '_>) =
+                        $raw_readonly;
+                    raw_readonly(value, ui, id, env);
+                }
+            }
+        };
+    }
+
+    #[rustfmt::skip]
+    matrix_ui!(mat4_ui mat4_ui_readonly Mat4, MatrixDisplay::Raw,
+        |value, ui, id, env| super::mat4_ui(value, ui, &(), id, env),
+        |value, ui, id, env| super::mat4_ui_readonly(value, ui, &(), id, env)
+    );
+    #[rustfmt::skip]
+    matrix_ui!(dmat4_ui dmat4_ui_readonly DMat4, MatrixDisplay::Raw,
+        |value, ui, id, env| super::dmat4_ui(value, ui, &(), id, env),
+        |value, ui, id, env| super::dmat4_ui_readonly(value, ui, &(), id, env)
+    );
+    #[rustfmt::skip]
+    matrix_ui!(affine2_ui affine2_ui_readonly Affine2, MatrixDisplay::Raw,
+        |value, ui, id, env| super::affine2_ui(value, ui, &(), id, env),
+        |value, ui, id, env| super::affine2_ui_readonly(value, ui, &(), id, env)
+    );
+    #[rustfmt::skip]
+    matrix_ui!(affine3a_ui affine3a_ui_readonly Affine3A, MatrixDisplay::Raw,
+        |value, ui, id, env| super::affine3a_ui(value, ui, &(), id, env),
+        |value, ui, id, env| super::affine3a_ui_readonly(value, ui, &(), id, env)
+    );
+    #[rustfmt::skip]
+    matrix_ui!(global_transform_ui global_transform_ui_readonly GlobalTransform, MatrixDisplay::Decomposed,
+        |value, ui, id, env| {
+            let mut affine = value.affine();
+            let changed = super::affine3a_ui(&mut affine, ui, &(), id, env);
+            if changed {
+                *value = GlobalTransform::from(affine);
+            }
+            changed
+        },
+        |value, ui, id, env| super::affine3a_ui_readonly(&value.affine(), ui, &(), id, env)
+    );
+
+    /// Multi-edits the matrices decomposed if their options say so, or else with `$raw_many`
+    macro_rules! matrix_ui_many {
+        ($name:ident $decomposed_many:ident $raw_many:path) => {
+            pub fn $name(
+                ui: &mut egui::Ui,
+                options: &dyn Any,
+                id: egui::Id,
+                env: InspectorUi<'_, '_>,
+                values: &mut [&mut dyn PartialReflect],
+                projector: &dyn ProjectorReflect,
+            ) -> bool {
+                let display = options
+                    .downcast_ref::<MatrixOptions>()
+                    .and_then(|options| options.display)
+                    .unwrap_or(MatrixDisplay::Raw);
+                match display {
+                    MatrixDisplay::Decomposed => {
+                        $decomposed_many(ui, options, id, env, values, projector)
+                    }
+                    MatrixDisplay::Raw => $raw_many(ui, options, id, env, values, projector),
+                }
+            }
+        };
+    }
+
+    many_ui!(mat4_ui_many_decomposed mat4_ui Mat4);
+    many_ui!(dmat4_ui_many_decomposed dmat4_ui DMat4);
+    matrix_ui_many!(mat4_ui_many mat4_ui_many_decomposed super::mat4_ui_many);
+    matrix_ui_many!(dmat4_ui_many dmat4_ui_many_decomposed super::dmat4_ui_many);
+    many_ui!(affine2_ui_many affine2_ui Affine2);
+    many_ui!(affine3a_ui_many affine3a_ui Affine3A);
+    many_ui!(global_transform_ui_many global_transform_ui GlobalTransform);
+
+    #[cfg(test)]
+    mod tests {
+        use bevy_math::{Affine2, DMat4, DVec3, prelude::*};
+
+        use super::Decompose;
+
+        #[test]
+        fn small_scales_can_be_decomposed() {
+            let scale = Vec3::splat(0.001);
+            let mat = Mat4::from_scale_rotation_translation(scale, Quat::IDENTITY, Vec3::X);
+            let (translation, _, decomposed_scale) = mat.decompose().unwrap();
+            assert_eq!(translation, Vec3::X);
+            assert!(decomposed_scale.abs_diff_eq(scale, 1e-7));
+
+            let mat = DMat4::from_scale(DVec3::splat(1e-6));
+            assert!(mat.decompose().is_some());
+            let affine = Affine2::from_scale(Vec2::splat(1e-4));
+            assert!(affine.decompose().is_some());
+        }
+
+        #[test]
+        fn singular_matrices_are_not_decomposed() {
+            assert!(
+                Mat4::from_scale(Vec3::new(1.0, 0.0, 1.0))
+                    .decompose()
+                    .is_none()
+            );
+            let flat = Mat4::from_cols(Vec4::X, Vec4::X * 1000.0, Vec4::Z, Vec4::W);
+            assert!(flat.decompose().is_none());
+            assert!(Mat4::ZERO.decompose().is_none());
+        }
+    }
+}
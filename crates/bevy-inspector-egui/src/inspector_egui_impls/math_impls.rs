@@ -0,0 +1,606 @@
+//! UI for `bevy_math` directions, rotations, rectangles and primitive shapes.
+
+use std::any::Any;
+
+use bevy_math::{
+    Dir2, Dir3, Dir3A, IRect, IVec2, Isometry2d, Isometry3d, Rect, Rot2, URect, UVec2, Vec2, Vec3,
+    Vec3A,
+    primitives::{Capsule2d, Capsule3d, Circle, Cuboid, Cylinder, Rectangle, Sphere},
+};
+use bevy_reflect::PartialReflect;
+use egui::{PointerButton, Sense, Stroke, StrokeKind, vec2};
+
+use super::InspectorPrimitive;
+use crate::{inspector_options::std_options::NumberOptions, reflect_inspector::InspectorUi};
+
+/// Side length of the direction pickers and shape previews
+const PREVIEW_SIZE: f32 = 48.0;
+
+impl InspectorPrimitive for Dir2 {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        ui.horizontal(|ui| {
+            let mut direction = None;
+            if let Some(picked) = circle_picker(ui, Vec2::from(*self)) {
+                direction = Some(picked);
+            }
+
+            let mut angle = self.to_angle();
+            if ui.drag_angle(&mut angle).changed() {
+                direction = Some(Vec2::from_angle(angle));
+            }
+
+            match direction.map(Dir2::new) {
+                Some(Ok(direction)) => {
+                    *self = direction;
+                    true
+                }
+                _ => false,
+            }
+        })
+        .inner
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = *self;
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
+
+impl InspectorPrimitive for Dir3 {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        match dir3_ui(Vec3::from(*self), ui, id, env).map(Dir3::new) {
+            Some(Ok(direction)) => {
+                *self = direction;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = *self;
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
+
+impl InspectorPrimitive for Dir3A {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        match dir3_ui(Vec3A::from(*self).into(), ui, id, env)
+            .map(|direction| Dir3A::new(direction.into()))
+        {
+            Some(Ok(direction)) => {
+                *self = direction;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = *self;
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
+
+/// Edits a direction with a sphere picker and its components. Returns the new, not yet normalized direction.
+fn dir3_ui(
+    direction: Vec3,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> Option<Vec3> {
+    ui.horizontal(|ui| {
+        let mut new_direction = sphere_picker(ui, direction);
+
+        let mut components = direction;
+        let options = NumberOptions::between(Vec3::NEG_ONE, Vec3::ONE).with_speed(0.01);
+        let changed = ui
+            .vertical(|ui| env.ui_for_reflect_with_options(&mut components, ui, id, &options))
+            .inner;
+        if changed {
+            new_direction = Some(components);
+        }
+
+        new_direction
+    })
+    .inner
+}
+
+/// Circle showing a 2d direction, which can be dragged to pick a new one.
+fn circle_picker(ui: &mut egui::Ui, direction: Vec2) -> Option<Vec2> {
+    let (rect, response) =
+        ui.allocate_exact_size(vec2(PREVIEW_SIZE, PREVIEW_SIZE), Sense::click_and_drag());
+    let center = rect.center();
+    let radius = rect.width() / 2.0 - 2.0;
+
+    let stroke = ui.style().interact(&response).fg_stroke;
+    let painter = ui.painter_at(rect);
+    painter.circle_stroke(
+        center,
+        radius,
+        ui.visuals().widgets.noninteractive.bg_stroke,
+    );
+    painter.arrow(
+        center,
+        vec2(direction.x, -direction.y) * radius,
+        Stroke::new(2.0, stroke.color),
+    );
+
+    let pointer = primary_pointer_pos(&response)?;
+    let picked = Vec2::new(pointer.x - center.x, center.y - pointer.y);
+    (picked != Vec2::ZERO).then_some(picked)
+}
+
+/// Pointer position if `response` was clicked or is dragged with the primary button
+fn primary_pointer_pos(response: &egui::Response) -> Option<egui::Pos2> {
+    if response.clicked() || response.dragged_by(PointerButton::Primary) {
+        response.interact_pointer_pos()
+    } else {
+        None
+    }
+}
+
+/// Disc showing the hemisphere of a 3d direction facing the viewer, with `+x` to the right and `+y` up.
+///
+/// Directions pointing away from the viewer are drawn hollow, right clicking flips the direction to the other hemisphere.
+fn sphere_picker(ui: &mut egui::Ui, direction: Vec3) -> Option<Vec3> {
+    let (rect, response) =
+        ui.allocate_exact_size(vec2(PREVIEW_SIZE, PREVIEW_SIZE), Sense::click_and_drag());
+    let center = rect.center();
+    let radius = rect.width() / 2.0 - 4.0;
+
+    let stroke = ui.style().interact(&response).fg_stroke;
+    let painter = ui.painter_at(rect);
+    painter.circle_stroke(
+        center,
+        radius,
+        ui.visuals().widgets.noninteractive.bg_stroke,
+    );
+    painter.line_segment(
+        [center - vec2(radius, 0.0), center + vec2(radius, 0.0)],
+        ui.visuals().widgets.noninteractive.bg_stroke,
+    );
+    let point = center + vec2(direction.x, -direction.y) * radius;
+    painter.line_segment([center, point], stroke);
+    if direction.z >= 0.0 {
+        painter.circle_filled(point, 3.0, stroke.color);
+    } else {
+        painter.circle_stroke(point, 3.0, stroke);
+    }
+
+    if response.secondary_clicked() {
+        return Some(direction * Vec3::new(1.0, 1.0, -1.0));
+    }
+
+    let pointer = primary_pointer_pos(&response)?;
+    let picked = Vec2::new(pointer.x - center.x, center.y - pointer.y) / radius;
+    let picked = picked.clamp_length_max(1.0);
+    let z = (1.0 - picked.length_squared()).max(0.0).sqrt();
+    let z = if direction.z < 0.0 { -z } else { z };
+    Some(picked.extend(z))
+}
+
+impl InspectorPrimitive for Rot2 {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        ui.horizontal(|ui| {
+            let mut angle = self.as_radians();
+            let mut changed = ui.drag_angle(&mut angle).changed();
+            if let Some(direction) = circle_picker(ui, Vec2::from_angle(angle)) {
+                angle = direction.to_angle();
+                changed = true;
+            }
+            if changed {
+                *self = Rot2::radians(angle);
+            }
+            changed
+        })
+        .inner
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
+        let mut degrees = self.as_degrees();
+        ui.add_enabled(false, egui::DragValue::new(&mut degrees).suffix("°"));
+    }
+}
+
+macro_rules! impl_isometry {
+    ($($ty:ty),*) => {
+        $(
+            impl InspectorPrimitive for $ty {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    _: &dyn Any,
+                    id: egui::Id,
+                    mut env: InspectorUi<'_, '_>,
+                ) -> bool {
+                    let mut changed = false;
+                    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+                        ui.label("Rotation");
+                        changed |= env.ui_for_reflect_with_options(&mut self.rotation, ui, id.with("rotation"), &());
+                        ui.end_row();
+                        ui.label("Translation");
+                        changed |= env.ui_for_reflect_with_options(&mut self.translation, ui, id.with("translation"), &());
+                        ui.end_row();
+                    });
+                    changed
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    _: &dyn Any,
+                    id: egui::Id,
+                    mut env: InspectorUi<'_, '_>,
+                ) {
+                    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+                        ui.label("Rotation");
+                        env.ui_for_reflect_readonly_with_options(&self.rotation, ui, id.with("rotation"), &());
+                        ui.end_row();
+                        ui.label("Translation");
+                        env.ui_for_reflect_readonly_with_options(&self.translation, ui, id.with("translation"), &());
+                        ui.end_row();
+                    });
+                }
+            }
+        )*
+    };
+}
+
+impl_isometry!(Isometry2d, Isometry3d);
+
+/// Rectangles which can be edited by their corners as well as their center and size
+trait RectEdit: Copy {
+    type Vec: PartialReflect + Copy;
+
+    fn corners(&mut self) -> (&mut Self::Vec, &mut Self::Vec);
+    /// Center and size, with the center rounded such that [`RectEdit::from_center_size`] gives back the same rectangle
+    fn center_size(self) -> (Self::Vec, Self::Vec);
+    fn from_center_size(center: Self::Vec, size: Self::Vec) -> Self;
+}
+
+impl RectEdit for Rect {
+    type Vec = Vec2;
+
+    fn corners(&mut self) -> (&mut Vec2, &mut Vec2) {
+        (&mut self.min, &mut self.max)
+    }
+    fn center_size(self) -> (Vec2, Vec2) {
+        (self.center(), self.size())
+    }
+    fn from_center_size(center: Vec2, size: Vec2) -> Self {
+        Rect::from_center_size(center, size.max(Vec2::ZERO))
+    }
+}
+
+impl RectEdit for URect {
+    type Vec = UVec2;
+
+    fn corners(&mut self) -> (&mut UVec2, &mut UVec2) {
+        (&mut self.min, &mut self.max)
+    }
+    fn center_size(self) -> (UVec2, UVec2) {
+        // `min` can be edited to be larger than `max`
+        let size = self.max.saturating_sub(self.min);
+        (self.min + size / 2, size)
+    }
+    fn from_center_size(center: UVec2, size: UVec2) -> Self {
+        let min = center.saturating_sub(size / 2);
+        URect::from_corners(min, min.saturating_add(size))
+    }
+}
+
+impl RectEdit for IRect {
+    type Vec = IVec2;
+
+    fn corners(&mut self) -> (&mut IVec2, &mut IVec2) {
+        (&mut self.min, &mut self.max)
+    }
+    fn center_size(self) -> (IVec2, IVec2) {
+        let size = self.size();
+        (self.min + size / 2, size)
+    }
+    fn from_center_size(center: IVec2, size: IVec2) -> Self {
+        let size = size.max(IVec2::ZERO);
+        let min = center.saturating_sub(size / 2);
+        IRect::from_corners(min, min.saturating_add(size))
+    }
+}
+
+fn rect_ui<R: RectEdit>(
+    rect: &mut R,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    let center_size_id = id.with("center_size");
+    let mut center_size = ui.data(|data| data.get_temp::<bool>(center_size_id).unwrap_or(false));
+
+    let mut changed = false;
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut center_size, false, "Min/Max");
+            ui.selectable_value(&mut center_size, true, "Center/Size");
+        });
+
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            if center_size {
+                let (mut center, mut size) = rect.center_size();
+                ui.label("Center");
+                let mut center_size_changed =
+                    env.ui_for_reflect_with_options(&mut center, ui, id.with("center"), &());
+                ui.end_row();
+                ui.label("Size");
+                center_size_changed |=
+                    env.ui_for_reflect_with_options(&mut size, ui, id.with("size"), &());
+                ui.end_row();
+
+                if center_size_changed {
+                    *rect = R::from_center_size(center, size);
+                    changed = true;
+                }
+            } else {
+                let (min, max) = rect.corners();
+                ui.label("Min");
+                changed |= env.ui_for_reflect_with_options(min, ui, id.with("min"), &());
+                ui.end_row();
+                ui.label("Max");
+                changed |= env.ui_for_reflect_with_options(max, ui, id.with("max"), &());
+                ui.end_row();
+            }
+        });
+    });
+
+    ui.data_mut(|data| data.insert_temp(center_size_id, center_size));
+    changed
+}
+
+macro_rules! impl_rect {
+    ($($ty:ty),*) => {
+        $(
+            impl InspectorPrimitive for $ty {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    _: &dyn Any,
+                    id: egui::Id,
+                    env: InspectorUi<'_, '_>,
+                ) -> bool {
+                    rect_ui(self, ui, id, env)
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    id: egui::Id,
+                    env: InspectorUi<'_, '_>,
+                ) {
+                    let mut copy = *self;
+                    ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+                }
+            }
+        )*
+    };
+}
+
+impl_rect!(Rect, URect, IRect);
+
+/// Implements [`InspectorPrimitive`] for a primitive shape, showing its non-negative fields next to a preview painted by `$preview`
+macro_rules! impl_shape {
+    ($($ty:ident { $($field:ident: $field_ty:ty),* } => $preview:ident),* $(,)?) => {
+        $(
+            impl InspectorPrimitive for $ty {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    _: &dyn Any,
+                    id: egui::Id,
+                    mut env: InspectorUi<'_, '_>,
+                ) -> bool {
+                    ui.horizontal(|ui| {
+                        let mut changed = false;
+                        ui.vertical(|ui| {
+                            egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+                                $(
+                                    ui.label(stringify!($field));
+                                    let options = NumberOptions::<$field_ty>::at_least(<$field_ty>::default());
+                                    changed |= env.ui_for_reflect_with_options(&mut self.$field, ui, id.with(stringify!($field)), &options);
+                                    ui.end_row();
+                                )*
+                            });
+                        });
+                        shape_preview(ui, |painter, rect, stroke| $preview(self, painter, rect, stroke));
+                        changed
+                    })
+                    .inner
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    id: egui::Id,
+                    env: InspectorUi<'_, '_>,
+                ) {
+                    let mut copy = *self;
+                    ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+                }
+            }
+        )*
+    };
+}
+
+impl_shape!(
+    Circle { radius: f32 } => preview_circle,
+    Sphere { radius: f32 } => preview_sphere,
+    Rectangle { half_size: Vec2 } => preview_rectangle,
+    Cuboid { half_size: Vec3 } => preview_cuboid,
+    Capsule2d { radius: f32, half_length: f32 } => preview_capsule2d,
+    Capsule3d { radius: f32, half_length: f32 } => preview_capsule3d,
+    Cylinder { radius: f32, half_height: f32 } => preview_cylinder,
+);
+
+fn shape_preview(ui: &mut egui::Ui, paint: impl FnOnce(&egui::Painter, egui::Rect, Stroke)) {
+    let (rect, _) = ui.allocate_exact_size(vec2(PREVIEW_SIZE, PREVIEW_SIZE), Sense::hover());
+    let stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+    paint(&ui.painter_at(rect), rect.shrink(2.0), stroke);
+}
+
+/// Scale which fits a shape of the given half extents into `rect`
+fn fit(half_extents: Vec2, rect: egui::Rect) -> f32 {
+    let scale = (rect.width() / 2.0 / half_extents.x).min(rect.height() / 2.0 / half_extents.y);
+    if scale.is_finite() { scale } else { 0.0 }
+}
+
+fn preview_circle(circle: &Circle, painter: &egui::Painter, rect: egui::Rect, stroke: Stroke) {
+    if circle.radius > 0.0 {
+        painter.circle_stroke(rect.center(), rect.width() / 2.0, stroke);
+    }
+}
+
+fn preview_sphere(sphere: &Sphere, painter: &egui::Painter, rect: egui::Rect, stroke: Stroke) {
+    if sphere.radius > 0.0 {
+        let radius = rect.width() / 2.0;
+        painter.circle_stroke(rect.center(), radius, stroke);
+        painter.add(egui::Shape::ellipse_stroke(
+            rect.center(),
+            vec2(radius, radius / 3.0),
+            stroke,
+        ));
+    }
+}
+
+fn preview_rectangle(
+    rectangle: &Rectangle,
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    stroke: Stroke,
+) {
+    let scale = fit(rectangle.half_size, rect);
+    let size = vec2(rectangle.half_size.x, rectangle.half_size.y) * 2.0 * scale;
+    painter.rect_stroke(
+        egui::Rect::from_center_size(rect.center(), size),
+        0.0,
+        stroke,
+        StrokeKind::Middle,
+    );
+}
+
+/// Front face, back face offset diagonally by half the depth, and the edges connecting them
+fn preview_cuboid(cuboid: &Cuboid, painter: &egui::Painter, rect: egui::Rect, stroke: Stroke) {
+    let Vec3 { x, y, z } = cuboid.half_size;
+    let depth = z / 2.0;
+    let scale = fit(Vec2::new(x + depth, y + depth), rect);
+
+    let front = egui::Rect::from_center_size(
+        rect.center() + vec2(-depth, depth) * scale,
+        vec2(x, y) * 2.0 * scale,
+    );
+    let back = front.translate(vec2(depth, -depth) * 2.0 * scale);
+
+    painter.rect_stroke(back, 0.0, stroke, StrokeKind::Middle);
+    for (a, b) in [
+        (front.left_top(), back.left_top()),
+        (front.right_top(), back.right_top()),
+        (front.left_bottom(), back.left_bottom()),
+        (front.right_bottom(), back.right_bottom()),
+    ] {
+        painter.line_segment([a, b], stroke);
+    }
+    painter.rect_stroke(front, 0.0, stroke, StrokeKind::Middle);
+}
+
+/// Capsule standing upright, like the bevy primitive
+fn paint_capsule(
+    radius: f32,
+    half_length: f32,
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    stroke: Stroke,
+) {
+    let scale = fit(Vec2::new(radius, radius + half_length), rect);
+    let size = vec2(radius, radius + half_length) * 2.0 * scale;
+    painter.rect_stroke(
+        egui::Rect::from_center_size(rect.center(), size),
+        radius * scale,
+        stroke,
+        StrokeKind::Middle,
+    );
+}
+
+fn preview_capsule2d(
+    capsule: &Capsule2d,
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    stroke: Stroke,
+) {
+    paint_capsule(capsule.radius, capsule.half_length, painter, rect, stroke);
+}
+
+fn preview_capsule3d(
+    capsule: &Capsule3d,
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    stroke: Stroke,
+) {
+    paint_capsule(capsule.radius, capsule.half_length, painter, rect, stroke);
+}
+
+/// Side view with the top and bottom ellipses
+fn preview_cylinder(
+    cylinder: &Cylinder,
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    stroke: Stroke,
+) {
+    let ellipse = cylinder.radius / 3.0;
+    let scale = fit(
+        Vec2::new(cylinder.radius, cylinder.half_height + ellipse),
+        rect,
+    );
+    let radius = vec2(cylinder.radius, ellipse) * scale;
+    let top = rect.center() - vec2(0.0, cylinder.half_height * scale);
+    let bottom = rect.center() + vec2(0.0, cylinder.half_height * scale);
+
+    painter.add(egui::Shape::ellipse_stroke(top, radius, stroke));
+    painter.add(egui::Shape::ellipse_stroke(bottom, radius, stroke));
+    painter.line_segment(
+        [top - vec2(radius.x, 0.0), bottom - vec2(radius.x, 0.0)],
+        stroke,
+    );
+    painter.line_segment(
+        [top + vec2(radius.x, 0.0), bottom + vec2(radius.x, 0.0)],
+        stroke,
+    );
+}
@@ -6,16 +6,26 @@ use crate::{
 };
 use bevy_platform::time::Instant;
 use bevy_reflect::{FromType, PartialReflect, Reflect, TypePath, TypeRegistry};
+use expression::NumberExpression;
 use std::{
     any::{Any, TypeId},
     borrow::Cow,
+    cell::Cell,
+    ffi::OsString,
+    net::SocketAddr,
+    num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize, Saturating, Wrapping,
+    },
     path::PathBuf,
 };
 
 mod bevy_impls;
+mod expression;
 mod glam_impls;
 #[cfg(feature = "bevy_image")]
 mod image;
+mod math_impls;
 mod std_impls;
 
 type InspectorEguiImplFn =
@@ -224,6 +234,110 @@ fn many_unimplemented<T: Any>(
     false
 }
 
+/// Many-variant for types without a [`Default`], like [`Entity`](bevy_ecs::entity::Entity) or [`Instant`].
+///
+/// Shows the regular UI seeded with the first value and writes the result to all values when it is changed.
+/// If the values differ, the number of distinct values is shown above it.
+fn many_from_first<T: InspectorPrimitive + PartialEq + Clone>(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> bool {
+    let mut distinct: Vec<T> = Vec::new();
+    for value in values.iter_mut() {
+        let value = projector(*value)
+            .try_downcast_ref::<T>()
+            .expect("non-fully-reflected value passed to many_from_first");
+        if !distinct.contains(value) {
+            distinct.push(value.clone());
+        }
+    }
+
+    let Some(mut temp) = distinct.first().cloned() else {
+        return false;
+    };
+    if distinct.len() > 1 {
+        ui.label(format!("{} different values", distinct.len()));
+    }
+
+    if !T::ui(&mut temp, ui, options, id, env) {
+        return false;
+    }
+    for value in values.iter_mut() {
+        *projector(*value).try_downcast_mut::<T>().unwrap() = temp.clone();
+    }
+    true
+}
+
+/// Many-variant for compound values like [`Rect`](bevy_math::Rect) or [`Cuboid`](bevy_math::primitives::Cuboid).
+///
+/// Like [`many_from_first`], but only the fields that were changed in the UI are written to the other values,
+/// so editing e.g. the `max` of multiple different rects keeps their `min`.
+fn many_changed_fields<T: InspectorPrimitive + Reflect + PartialEq + Clone>(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> bool {
+    let mut distinct: Vec<T> = Vec::new();
+    for value in values.iter_mut() {
+        let value = projector(*value)
+            .try_downcast_ref::<T>()
+            .expect("non-fully-reflected value passed to many_changed_fields");
+        if !distinct.contains(value) {
+            distinct.push(value.clone());
+        }
+    }
+
+    let Some(first) = distinct.first() else {
+        return false;
+    };
+    if distinct.len() > 1 {
+        ui.label(format!("{} different values", distinct.len()));
+    }
+
+    let mut temp = first.clone();
+    if !T::ui(&mut temp, ui, options, id, env) {
+        return false;
+    }
+    for value in values.iter_mut() {
+        apply_changed_fields(first, &temp, projector(*value));
+    }
+    true
+}
+
+/// Applies the fields that differ between `old` and `new` to `target`, or all of `new` if it isn't a struct
+fn apply_changed_fields(
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    target: &mut dyn PartialReflect,
+) {
+    let (bevy_reflect::ReflectRef::Struct(old), bevy_reflect::ReflectRef::Struct(new)) =
+        (old.reflect_ref(), new.reflect_ref())
+    else {
+        target.apply(new);
+        return;
+    };
+    let bevy_reflect::ReflectMut::Struct(target) = target.reflect_mut() else {
+        target.apply(new.as_partial_reflect());
+        return;
+    };
+    for (i, new_field) in new.iter_fields().enumerate() {
+        let unchanged = old
+            .field_at(i)
+            .and_then(|old_field| old_field.reflect_partial_eq(new_field))
+            .unwrap_or(false);
+        if !unchanged && let Some(target_field) = target.field_at_mut(i) {
+            target_field.apply(new_field);
+        }
+    }
+}
+
 fn add<T: InspectorPrimitive + TypePath + PartialEq + Clone>(type_registry: &mut TypeRegistry) {
     type_registry.register_type_data::<T, InspectorEguiImpl>();
 }
@@ -264,9 +378,14 @@ pub fn register_std_impls(type_registry: &mut TypeRegistry) {
     add_of_with_many::<u32>(type_registry, std_impls::number_ui_many::<u32>);
     add_of_with_many::<u64>(type_registry, std_impls::number_ui_many::<u64>);
     add_of_with_many::<usize>(type_registry, std_impls::number_ui_many::<usize>);
+    add_of_with_many::<i128>(type_registry, ui_many_vtable::<i128>);
+    add_of_with_many::<u128>(type_registry, ui_many_vtable::<u128>);
     add::<bool>(type_registry);
+    add_of_with_many::<char>(type_registry, ui_many_vtable::<char>);
     add::<String>(type_registry);
     add::<Cow<str>>(type_registry);
+    type_registry.register::<OsString>();
+    add_of_with_many::<OsString>(type_registry, ui_many_vtable::<OsString>);
     type_registry.register::<PathBuf>();
     add::<PathBuf>(type_registry);
 
@@ -280,7 +399,33 @@ pub fn register_std_impls(type_registry: &mut TypeRegistry) {
     add::<TypeId>(type_registry);
 
     add::<std::time::Duration>(type_registry);
-    add_of_with_many::<Instant>(type_registry, many_unimplemented::<Instant>);
+    add_of_with_many::<Instant>(type_registry, many_from_first::<Instant>);
+
+    // `IpAddr`, `SystemTime` and `Ordering` aren't supported, `bevy_reflect` doesn't implement `Reflect` for them
+    type_registry.register::<SocketAddr>();
+    add_of_with_many::<SocketAddr>(type_registry, many_from_first::<SocketAddr>);
+
+    macro_rules! add_nonzero {
+        ($($ty:ty),*) => {$(
+            type_registry.register::<$ty>();
+            add_of_with_many::<$ty>(type_registry, std_impls::nonzero_ui_many::<$ty>);
+        )*};
+    }
+    add_nonzero!(NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize);
+    type_registry.register::<NonZeroI128>();
+    type_registry.register::<NonZeroU128>();
+    add_of_with_many::<NonZeroI128>(type_registry, many_from_first::<NonZeroI128>);
+    add_of_with_many::<NonZeroU128>(type_registry, many_from_first::<NonZeroU128>);
+
+    macro_rules! add_wrapping {
+        ($($ty:ty),*) => {$(
+            type_registry.register::<Wrapping<$ty>>();
+            type_registry.register::<Saturating<$ty>>();
+            add_of_with_many::<Wrapping<$ty>>(type_registry, std_impls::wrapping_ui_many::<$ty>);
+            add_of_with_many::<Saturating<$ty>>(type_registry, std_impls::saturating_ui_many::<$ty>);
+        )*};
+    }
+    add_wrapping!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
 }
 
 /// Register [`InspectorEguiImpl`]s for [`bevy_math`]/`glam` types
@@ -299,44 +444,78 @@ pub fn register_glam_impls(type_registry: &mut TypeRegistry) {
     add_raw::<bevy_math::DVec2>(type_registry, glam_impls::dvec2_ui, glam_impls::dvec2_ui_readonly, glam_impls::dvec2_ui_many);
     add_raw::<bevy_math::DVec3>(type_registry, glam_impls::dvec3_ui, glam_impls::dvec3_ui_readonly, glam_impls::dvec3_ui_many);
     add_raw::<bevy_math::DVec4>(type_registry, glam_impls::dvec4_ui, glam_impls::dvec4_ui_readonly, glam_impls::dvec4_ui_many);
-    add_raw::<bevy_math::BVec2>(type_registry, glam_impls::bvec2_ui, glam_impls::bvec2_ui_readonly, many_unimplemented::<bevy_math::BVec2>);
-    add_raw::<bevy_math::BVec3>(type_registry, glam_impls::bvec3_ui, glam_impls::bvec3_ui_readonly, many_unimplemented::<bevy_math::BVec3>);
-    add_raw::<bevy_math::BVec4>(type_registry, glam_impls::bvec4_ui, glam_impls::bvec4_ui_readonly, many_unimplemented::<bevy_math::BVec4>);
-    add_raw::<bevy_math::Mat2>(type_registry, glam_impls::mat2_ui, glam_impls::mat2_ui_readonly, many_unimplemented::<bevy_math::Mat2>);
-    add_raw::<bevy_math::Mat3>(type_registry, glam_impls::mat3_ui, glam_impls::mat3_ui_readonly, many_unimplemented::<bevy_math::Mat3>);
-    add_raw::<bevy_math::Mat3A>(type_registry, glam_impls::mat3a_ui, glam_impls::mat3a_ui_readonly, many_unimplemented::<bevy_math::Mat3A>);
-    add_raw::<bevy_math::Mat4>(type_registry, glam_impls::mat4_ui, glam_impls::mat4_ui_readonly, many_unimplemented::<bevy_math::Mat4>);
-    add_raw::<bevy_math::DMat2>(type_registry, glam_impls::dmat2_ui, glam_impls::dmat2_ui_readonly, many_unimplemented::<bevy_math::DMat2>);
-    add_raw::<bevy_math::DMat3>(type_registry, glam_impls::dmat3_ui, glam_impls::dmat3_ui_readonly, many_unimplemented::<bevy_math::DMat3>);
-    add_raw::<bevy_math::DMat4>(type_registry, glam_impls::dmat4_ui, glam_impls::dmat4_ui_readonly, many_unimplemented::<bevy_math::DMat4>);
+    add_raw::<bevy_math::BVec2>(type_registry, glam_impls::bvec2_ui, glam_impls::bvec2_ui_readonly, glam_impls::bvec2_ui_many);
+    add_raw::<bevy_math::BVec3>(type_registry, glam_impls::bvec3_ui, glam_impls::bvec3_ui_readonly, glam_impls::bvec3_ui_many);
+    add_raw::<bevy_math::BVec4>(type_registry, glam_impls::bvec4_ui, glam_impls::bvec4_ui_readonly, glam_impls::bvec4_ui_many);
+    add_raw::<bevy_math::Mat2>(type_registry, glam_impls::mat2_ui, glam_impls::mat2_ui_readonly, glam_impls::mat2_ui_many);
+    add_raw::<bevy_math::Mat3>(type_registry, glam_impls::mat3_ui, glam_impls::mat3_ui_readonly, glam_impls::mat3_ui_many);
+    add_raw::<bevy_math::Mat3A>(type_registry, glam_impls::mat3a_ui, glam_impls::mat3a_ui_readonly, glam_impls::mat3a_ui_many);
+    add_raw::<bevy_math::Mat4>(type_registry, glam_impls::decomposed::mat4_ui, glam_impls::decomposed::mat4_ui_readonly, glam_impls::decomposed::mat4_ui_many);
+    add_raw::<bevy_math::DMat2>(type_registry, glam_impls::dmat2_ui, glam_impls::dmat2_ui_readonly, glam_impls::dmat2_ui_many);
+    add_raw::<bevy_math::DMat3>(type_registry, glam_impls::dmat3_ui, glam_impls::dmat3_ui_readonly, glam_impls::dmat3_ui_many);
+    add_raw::<bevy_math::DMat4>(type_registry, glam_impls::decomposed::dmat4_ui, glam_impls::decomposed::dmat4_ui_readonly, glam_impls::decomposed::dmat4_ui_many);
+    add_raw::<bevy_math::Affine2>(type_registry, glam_impls::decomposed::affine2_ui, glam_impls::decomposed::affine2_ui_readonly, glam_impls::decomposed::affine2_ui_many);
+    add_raw::<bevy_math::Affine3A>(type_registry, glam_impls::decomposed::affine3a_ui, glam_impls::decomposed::affine3a_ui_readonly, glam_impls::decomposed::affine3a_ui_many);
 
     add_raw::<bevy_math::Quat>(type_registry, glam_impls::quat::quat_ui, glam_impls::quat::quat_ui_readonly, glam_impls::quat::quat_ui_many);
+
+    macro_rules! register_and_add {
+        ($($ty:ty => $many:ident),* $(,)?) => {$(
+            type_registry.register::<$ty>();
+            add_of_with_many::<$ty>(type_registry, $many::<$ty>);
+        )*};
+    }
+    use bevy_math::primitives::{Capsule2d, Capsule3d, Circle, Cuboid, Cylinder, Rectangle, Sphere};
+    register_and_add!(
+        bevy_math::Dir2 => many_from_first,
+        bevy_math::Dir3 => many_from_first,
+        bevy_math::Dir3A => many_from_first,
+        bevy_math::Rot2 => many_from_first,
+        bevy_math::Isometry2d => many_changed_fields,
+        bevy_math::Isometry3d => many_changed_fields,
+        bevy_math::Rect => many_changed_fields,
+        bevy_math::URect => many_changed_fields,
+        bevy_math::IRect => many_changed_fields,
+        Circle => many_changed_fields,
+        Sphere => many_changed_fields,
+        Rectangle => many_changed_fields,
+        Cuboid => many_changed_fields,
+        Capsule2d => many_changed_fields,
+        Capsule3d => many_changed_fields,
+        Cylinder => many_changed_fields,
+    );
 }
 
 /// Register [`InspectorEguiImpl`]s for `bevy` types
 #[rustfmt::skip]
 pub fn register_bevy_impls(type_registry: &mut TypeRegistry) {
-    add_of_with_many::<bevy_ecs::entity::Entity>(type_registry, many_unimplemented::<bevy_ecs::entity::Entity>);
+    add_of_with_many::<bevy_ecs::entity::Entity>(type_registry, many_from_first::<bevy_ecs::entity::Entity>);
     add::<bevy_color::Color>(type_registry);
+    type_registry.register::<bevy_transform::components::GlobalTransform>();
+    add_raw::<bevy_transform::components::GlobalTransform>(type_registry, glam_impls::decomposed::global_transform_ui, glam_impls::decomposed::global_transform_ui_readonly, glam_impls::decomposed::global_transform_ui_many);
 
     #[cfg(feature = "bevy_render")] 
     {
-      add_of_with_many::<bevy_asset::Handle<bevy_render::mesh::Mesh>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_render::mesh::Mesh>>);
+      add_of_with_many::<bevy_asset::Handle<bevy_render::mesh::Mesh>>(type_registry, bevy_impls::mesh_ui_many);
       add::<bevy_render::view::RenderLayers>(type_registry);
     }
     #[cfg(feature = "bevy_image")]
     {
-      add_of_with_many::<bevy_asset::Handle<bevy_image::Image>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_image::Image>>);
+      add_of_with_many::<bevy_asset::Handle<bevy_image::Image>>(type_registry, ui_many_vtable::<bevy_asset::Handle<bevy_image::Image>>);
     }
 
     add::<uuid::Uuid>(type_registry);
 }
 
+/// Drag value editing multiple numbers at once.
+///
+/// `f` is called with the new value if all values were the same, the dragged difference,
+/// or the typed expression which is applied to each value separately, so that e.g. `*2` scales all values.
 pub(crate) fn change_slider<T>(
     ui: &mut egui::Ui,
     id: egui::Id,
     same: Option<T>,
-    f: impl FnOnce(T, bool),
+    f: impl FnOnce(NumberExpression),
 ) -> bool
 where
     T: egui::emath::Numeric + std::ops::Sub<Output = T> + Default + Send + Sync + 'static,
@@ -345,11 +524,17 @@ where
 
     match same {
         Some(mut same) => {
-            let widget = egui::DragValue::new(&mut same).speed(speed);
+            let current = same.to_f64();
+            let widget = egui::DragValue::new(&mut same)
+                .speed(speed)
+                .update_while_editing(false)
+                .custom_parser(|text| {
+                    NumberExpression::parse(text).map(|expression| expression.apply(current))
+                });
 
             let changed = ui.add(widget).changed();
             if changed {
-                f(same, true);
+                f(NumberExpression::Absolute(same.to_f64()));
             }
 
             changed
@@ -358,13 +543,22 @@ where
             let old_change = ui.memory_mut(|memory| *memory.data.get_temp_mut_or_default::<T>(id));
             let mut change = old_change;
 
+            let typed = Cell::new(None);
             let widget = egui::DragValue::new(&mut change)
                 .speed(speed)
-                .custom_formatter(|_, _| "-".to_string());
-
-            let changed = ui.add(widget).changed();
-            if changed {
-                f(change - old_change, false);
+                .custom_formatter(|_, _| "-".to_string())
+                .update_while_editing(false)
+                .custom_parser(|text| {
+                    typed.set(NumberExpression::parse(text));
+                    None
+                });
+
+            let mut changed = ui.add(widget).changed();
+            if let Some(expression) = typed.take() {
+                f(expression);
+                changed = true;
+            } else if changed {
+                f(NumberExpression::Add((change - old_change).to_f64()));
             }
 
             ui.memory_mut(|memory| *memory.data.get_temp_mut_or_default(id) = change);
@@ -409,3 +603,21 @@ macro_rules! many_ui {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::apply_changed_fields;
+    use bevy_math::{Rect, Vec2};
+
+    #[test]
+    fn multi_edit_keeps_unchanged_fields() {
+        let first = Rect::new(0.0, 0.0, 1.0, 1.0);
+        let mut edited = first;
+        edited.max = Vec2::new(4.0, 4.0);
+
+        let mut other = Rect::new(-2.0, -2.0, 3.0, 3.0);
+        apply_changed_fields(&first, &edited, &mut other);
+        assert_eq!(other.min, Vec2::new(-2.0, -2.0));
+        assert_eq!(other.max, Vec2::new(4.0, 4.0));
+    }
+}
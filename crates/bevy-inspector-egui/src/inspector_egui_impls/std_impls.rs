@@ -1,16 +1,31 @@
-use std::{any::TypeId, borrow::Cow, ops::AddAssign, path::PathBuf};
+use std::{
+    any::TypeId,
+    borrow::Cow,
+    ffi::OsString,
+    fmt::Display,
+    net::SocketAddr,
+    num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize, Saturating, Wrapping,
+    },
+    path::PathBuf,
+    str::FromStr,
+};
 
 use bevy_platform::time::Instant;
 use bevy_reflect::{PartialReflect, Reflect, TypePath};
-use egui::{DragValue, RichText, TextBuffer};
+use egui::{DragValue, RichText, TextBuffer, emath::Numeric};
 
-use super::{InspectorPrimitive, InspectorUi, change_slider, iter_all_eq};
+use super::{
+    InspectorPrimitive, InspectorUi, change_slider, expression::NumberExpression, iter_all_eq,
+};
 use crate::{
+    dropdown::DropDownBox,
     inspector_options::{
         InspectorOptionsType,
-        std_options::{NumberDisplay, NumberOptions, RangeOptions},
+        std_options::{NumberDisplay, NumberOptions, RangeOptions, StringOptions, Unit},
     },
-    reflect_inspector::ProjectorReflect,
+    reflect_inspector::{ProjectorReflect, errors},
 };
 use std::{any::Any, time::Duration};
 
@@ -53,20 +68,7 @@ impl<T: Reflect + Num> InspectorPrimitive for T {
             .downcast_ref::<NumberOptions<T>>()
             .cloned()
             .unwrap_or_default();
-        let decimal_range = 0..=1usize;
-        ui.add(
-            egui::Button::new(
-                RichText::new(format!(
-                    "{}{}{}",
-                    options.prefix,
-                    egui::emath::format_with_decimals_in_range(self.to_f64(), decimal_range),
-                    options.suffix
-                ))
-                .monospace(),
-            )
-            .truncate()
-            .sense(egui::Sense::hover()),
-        );
+        display_number_readonly(*self, &options, ui);
     }
 }
 
@@ -96,14 +98,28 @@ pub fn number_ui_readonly<T: egui::emath::Numeric>(
         .downcast_ref::<NumberOptions<T>>()
         .cloned()
         .unwrap_or_default();
-    let decimal_range = 0..=1usize;
+    display_number_readonly(*value, &options, ui);
+}
+
+fn display_number_readonly<T: egui::emath::Numeric>(
+    value: T,
+    options: &NumberOptions<T>,
+    ui: &mut egui::Ui,
+) {
+    let (value, suffix) = match options.unit {
+        Some(unit) => (unit.from_base(value.to_f64()), unit_suffix(options, unit)),
+        None => (value.to_f64(), options.suffix.as_str()),
+    };
+    let decimal_range = options
+        .decimals
+        .map_or(0..=1, |decimals| decimals..=decimals);
     ui.add(
         egui::Button::new(
             RichText::new(format!(
                 "{}{}{}",
                 options.prefix,
-                egui::emath::format_with_decimals_in_range(value.to_f64(), decimal_range),
-                options.suffix
+                egui::emath::format_with_decimals_in_range(value, decimal_range),
+                suffix
             ))
             .monospace(),
         )
@@ -112,12 +128,47 @@ pub fn number_ui_readonly<T: egui::emath::Numeric>(
     );
 }
 
+fn unit_suffix<T>(options: &NumberOptions<T>, unit: Unit) -> &str {
+    if options.suffix.is_empty() {
+        unit.symbol()
+    } else {
+        &options.suffix
+    }
+}
+
+/// Edits the value converted to the [`NumberOptions::unit`], with all options converted accordingly
+fn display_number_in_unit<T: egui::emath::Numeric>(
+    value: &mut T,
+    options: &NumberOptions<T>,
+    unit: Unit,
+    ui: &mut egui::Ui,
+    default_speed: f32,
+) -> bool {
+    let mut unit_options = options.map(|value| unit.from_base(value.to_f64()));
+    unit_options.step = options.step.map(|step| unit.from_base(step));
+    unit_options.suffix = unit_suffix(options, unit).to_owned();
+    unit_options.display = options.display;
+    unit_options.unit = None;
+
+    let mut displayed = unit.from_base(value.to_f64());
+    let changed = display_number(&mut displayed, &unit_options, ui, default_speed);
+    if changed {
+        *value = T::from_f64(unit.to_base(displayed));
+    }
+    changed
+}
+
 fn display_number<T: egui::emath::Numeric>(
     value: &mut T,
     options: &NumberOptions<T>,
     ui: &mut egui::Ui,
     default_speed: f32,
 ) -> bool {
+    if let Some(unit) = options.unit {
+        return display_number_in_unit(value, options, unit, ui, default_speed);
+    }
+
+    let value_f64 = value.to_f64();
     let mut changed = match options.display {
         NumberDisplay::Drag => {
             let mut widget = egui::DragValue::new(value);
@@ -133,70 +184,142 @@ fn display_number<T: egui::emath::Numeric>(
                 (None, Some(max)) => widget = widget.range(f64::MIN..=max.to_f64()),
                 (None, None) => {}
             }
-            if options.speed != 0.0 {
-                widget = widget.speed(options.speed);
+            let speed = if options.speed != 0.0 {
+                options.speed
             } else {
-                widget = widget.speed(default_speed);
+                default_speed
+            };
+            if options.logarithmic {
+                widget = widget.speed(speed as f64 * value_f64.abs().max(1e-3));
+            } else {
+                widget = widget.speed(speed);
+            }
+            if let Some(decimals) = options.decimals {
+                widget = widget.fixed_decimals(decimals);
             }
+            widget = widget
+                .update_while_editing(false)
+                .custom_parser(number_parser(value_f64, options));
             ui.add(widget).changed()
         }
         NumberDisplay::Slider => {
-            let min = options.min.unwrap_or_else(|| T::from_f64(0.0));
-            let max = options.max.unwrap_or_else(|| T::from_f64(1.0));
+            let min = options
+                .soft_min
+                .or(options.min)
+                .unwrap_or_else(|| T::from_f64(0.0));
+            let max = options
+                .soft_max
+                .or(options.max)
+                .unwrap_or_else(|| T::from_f64(1.0));
             let range = min..=max;
-            let widget = egui::Slider::new(value, range);
+            let mut widget = egui::Slider::new(value, range)
+                .custom_parser(number_parser(value_f64, options))
+                .logarithmic(options.logarithmic)
+                .clamping(egui::SliderClamping::Never);
+            if let Some(step) = options.step {
+                widget = widget.step_by(step);
+            }
+            if let Some(decimals) = options.decimals {
+                widget = widget.fixed_decimals(decimals);
+            }
             ui.add(widget).changed()
         }
     };
 
+    if changed {
+        *value = T::from_f64(snap_to_step(value.to_f64(), options));
+    }
+    let clamped = clamp_to_options(value.to_f64(), options);
+    if clamped != value.to_f64() {
+        *value = T::from_f64(clamped);
+        changed = true;
+    }
+    changed
+}
+
+/// Parser for text typed into a number field, evaluating expressions like `2*pi` or `+=0.5` relative to the `current` value
+fn number_parser<T: egui::emath::Numeric>(
+    current: f64,
+    options: &NumberOptions<T>,
+) -> impl Fn(&str) -> Option<f64> + '_ {
+    move |text| {
+        NumberExpression::parse(text)
+            .map(|expression| apply_expression(expression, current, options))
+    }
+}
+
+fn snap_to_step<T>(value: f64, options: &NumberOptions<T>) -> f64 {
+    match options.step {
+        Some(step) if step > 0.0 => (value / step).round() * step,
+        _ => value,
+    }
+}
+
+pub(crate) fn from_base_unit<T>(value: f64, options: &NumberOptions<T>) -> f64 {
+    options.unit.map_or(value, |unit| unit.from_base(value))
+}
+
+/// Applies an expression typed in the displayed unit to a stored value, and constrains the result to the options
+pub(crate) fn apply_expression<T: egui::emath::Numeric>(
+    expression: NumberExpression,
+    value: f64,
+    options: &NumberOptions<T>,
+) -> f64 {
+    let new_value = match options.unit {
+        Some(unit) => unit.to_base(expression.apply(unit.from_base(value))),
+        None => expression.apply(value),
+    };
+    constrain_to_options(new_value, options)
+}
+
+/// Snaps an edited value to the [`NumberOptions::step`] and clamps it between `min` and `max`.
+pub(crate) fn constrain_to_options<T: egui::emath::Numeric>(
+    value: f64,
+    options: &NumberOptions<T>,
+) -> f64 {
+    clamp_to_options(snap_to_step(value, options), options)
+}
+
+fn clamp_to_options<T: egui::emath::Numeric>(value: f64, options: &NumberOptions<T>) -> f64 {
+    let mut value = value;
     if let Some(min) = options.min {
-        let as_f64 = value.to_f64();
-        let min = min.to_f64();
-        if as_f64 < min {
-            *value = T::from_f64(min);
-            changed = true;
-        }
+        value = value.max(min.to_f64());
     }
     if let Some(max) = options.max {
-        let as_f64 = value.to_f64();
-        let max = max.to_f64();
-        if as_f64 > max {
-            *value = T::from_f64(max);
-            changed = true;
-        }
+        value = value.min(max.to_f64());
     }
-    changed
+    value
 }
 
 pub fn number_ui_many<T>(
     ui: &mut egui::Ui,
-    _: &dyn Any,
+    options: &dyn Any,
     id: egui::Id,
     _env: InspectorUi<'_, '_>,
     values: &mut [&mut dyn PartialReflect],
     projector: &dyn ProjectorReflect,
 ) -> bool
 where
-    T: Reflect + egui::emath::Numeric + AddAssign<T>,
+    T: Reflect + egui::emath::Numeric,
 {
+    let options = options
+        .downcast_ref::<NumberOptions<T>>()
+        .cloned()
+        .unwrap_or_default();
+
     let same = iter_all_eq(
         values
             .iter_mut()
             .map(|value| *projector(*value).try_downcast_ref::<T>().unwrap()),
     )
-    .map(T::to_f64);
+    .map(|value| from_base_unit(value.to_f64(), &options));
 
-    change_slider(ui, id, same, |change, overwrite| {
+    change_slider(ui, id, same, |expression| {
         for value in values.iter_mut() {
             let value = projector(*value)
                 .try_downcast_mut::<T>()
                 .expect("non-fully-reflected value passed to number_ui_many");
-            let change = T::from_f64(change);
-            if overwrite {
-                *value = change;
-            } else {
-                *value += change;
-            }
+            *value = T::from_f64(apply_expression(expression, value.to_f64(), &options));
         }
     })
 }
@@ -221,32 +344,45 @@ impl InspectorPrimitive for bool {
 }
 
 impl InspectorPrimitive for String {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        if self.contains('\n') {
-            ui.text_edit_multiline(self).changed()
-        } else {
-            ui.text_edit_singleline(self).changed()
-        }
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let default = StringOptions::default();
+        let options = options.downcast_ref::<StringOptions>().unwrap_or(&default);
+        string_ui(self, ui, options, id)
     }
 
-    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        if self.contains('\n') {
-            ui.text_edit_multiline(&mut self.as_str());
-        } else {
-            ui.text_edit_singleline(&mut self.as_str());
-        }
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let default = StringOptions::default();
+        let options = options.downcast_ref::<StringOptions>().unwrap_or(&default);
+        let multiline = self.contains('\n');
+        ui.add(text_edit(&mut self.as_str(), options, multiline));
     }
 }
 
 impl InspectorPrimitive for Cow<'static, str> {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        let mut clone = self.to_string();
-        let changed = if self.contains('\n') {
-            ui.text_edit_multiline(&mut clone).changed()
-        } else {
-            ui.text_edit_singleline(&mut clone).changed()
-        };
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let default = StringOptions::default();
+        let options = options.downcast_ref::<StringOptions>().unwrap_or(&default);
 
+        let mut clone = self.to_string();
+        let changed = string_ui(&mut clone, ui, options, id);
         if changed {
             *self = Cow::Owned(clone);
         }
@@ -254,13 +390,62 @@ impl InspectorPrimitive for Cow<'static, str> {
         changed
     }
 
-    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        if self.contains('\n') {
-            ui.text_edit_multiline(&mut self.as_str());
-        } else {
-            ui.text_edit_singleline(&mut self.as_str());
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let default = StringOptions::default();
+        let options = options.downcast_ref::<StringOptions>().unwrap_or(&default);
+        let multiline = self.contains('\n');
+        ui.add(text_edit(&mut self.as_ref(), options, multiline));
+    }
+}
+
+fn string_ui(value: &mut String, ui: &mut egui::Ui, options: &StringOptions, id: egui::Id) -> bool {
+    let multiline = value.contains('\n');
+    if !options.suggestions.is_none() {
+        let mut dropdown = DropDownBox::from_iter(
+            options.suggestions.get(),
+            id.with("suggestions"),
+            value,
+            |ui, text| ui.selectable_label(false, text),
+        )
+        .hint_text(options.hint_text.as_str())
+        .multiline(options.multiline || multiline)
+        .code_editor(options.code)
+        .password(options.password);
+        if let Some(max_length) = options.max_length {
+            dropdown = dropdown.char_limit(max_length);
         }
+        return ui.add(dropdown).changed();
+    }
+
+    ui.add(text_edit(value, options, multiline)).changed()
+}
+
+fn text_edit<'t>(
+    text: &'t mut dyn TextBuffer,
+    options: &StringOptions,
+    multiline: bool,
+) -> egui::TextEdit<'t> {
+    let mut edit = if options.code {
+        egui::TextEdit::multiline(text).code_editor()
+    } else if options.multiline || multiline {
+        egui::TextEdit::multiline(text)
+    } else {
+        egui::TextEdit::singleline(text)
+    };
+    edit = edit.password(options.password);
+    if !options.hint_text.is_empty() {
+        edit = edit.hint_text(options.hint_text.as_str());
     }
+    if let Some(max_length) = options.max_length {
+        edit = edit.char_limit(max_length);
+    }
+    edit
 }
 
 impl InspectorPrimitive for Duration {
@@ -487,3 +672,415 @@ impl InspectorPrimitive for TypeId {
         ui.label(str);
     }
 }
+
+impl<T: Num> InspectorPrimitive for Wrapping<T>
+where
+    Wrapping<T>: Reflect,
+{
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<NumberOptions<T>>()
+            .cloned()
+            .unwrap_or_default();
+        display_number(&mut self.0, &options, ui, 0.1)
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let options = options
+            .downcast_ref::<NumberOptions<T>>()
+            .cloned()
+            .unwrap_or_default();
+        display_number_readonly(self.0, &options, ui);
+    }
+}
+
+impl<T: Num> InspectorPrimitive for Saturating<T>
+where
+    Saturating<T>: Reflect,
+{
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<NumberOptions<T>>()
+            .cloned()
+            .unwrap_or_default();
+        display_number(&mut self.0, &options, ui, 0.1)
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let options = options
+            .downcast_ref::<NumberOptions<T>>()
+            .cloned()
+            .unwrap_or_default();
+        display_number_readonly(self.0, &options, ui);
+    }
+}
+
+pub fn wrapping_ui_many<T>(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> bool
+where
+    T: Reflect + egui::emath::Numeric,
+    Wrapping<T>: Reflect,
+{
+    number_ui_many::<T>(ui, options, id, env, values, &|value| {
+        &mut projector(value)
+            .try_downcast_mut::<Wrapping<T>>()
+            .expect("non-fully-reflected value passed to wrapping_ui_many")
+            .0
+    })
+}
+
+pub fn saturating_ui_many<T>(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> bool
+where
+    T: Reflect + egui::emath::Numeric,
+    Saturating<T>: Reflect,
+{
+    number_ui_many::<T>(ui, options, id, env, values, &|value| {
+        &mut projector(value)
+            .try_downcast_mut::<Saturating<T>>()
+            .expect("non-fully-reflected value passed to saturating_ui_many")
+            .0
+    })
+}
+
+pub(super) trait NonZeroNum: Copy + 'static {
+    type Num: egui::emath::Numeric;
+
+    fn get(self) -> Self::Num;
+    fn new(value: Self::Num) -> Option<Self>;
+}
+
+macro_rules! impl_nonzero {
+    ($($nonzero:ty => $num:ty),*) => {
+        $(
+            impl NonZeroNum for $nonzero {
+                type Num = $num;
+
+                fn get(self) -> $num {
+                    <$nonzero>::get(self)
+                }
+                fn new(value: $num) -> Option<Self> {
+                    <$nonzero>::new(value)
+                }
+            }
+
+            impl InspectorPrimitive for $nonzero {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    _: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) -> bool {
+                    let options = options
+                        .downcast_ref::<NumberOptions<$num>>()
+                        .cloned()
+                        .unwrap_or_default();
+                    nonzero_ui(self, &options, ui)
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    _: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) {
+                    let options = options
+                        .downcast_ref::<NumberOptions<$num>>()
+                        .cloned()
+                        .unwrap_or_default();
+                    display_number_readonly(self.get(), &options, ui);
+                }
+            }
+        )*
+    };
+}
+
+impl_nonzero!(
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64, NonZeroIsize => isize,
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64, NonZeroUsize => usize
+);
+
+fn nonzero_ui<T: NonZeroNum>(
+    value: &mut T,
+    options: &NumberOptions<T::Num>,
+    ui: &mut egui::Ui,
+) -> bool {
+    let mut number = value.get();
+    if !display_number(&mut number, options, ui, 0.1) {
+        return false;
+    }
+    let new = nonzero_or_skip_zero(*value, number);
+    let changed = new.get() != value.get();
+    *value = new;
+    changed
+}
+
+/// Zero is skipped in the direction of the change, so that dragging a signed value from `1` goes to `-1`.
+/// Unsigned values stay at `1`.
+fn nonzero_or_skip_zero<T: NonZeroNum>(old: T, new: T::Num) -> T {
+    T::new(new)
+        .or_else(|| {
+            let towards = if old.get().to_f64() > 0.0 { -1.0 } else { 1.0 };
+            T::new(T::Num::from_f64(towards))
+        })
+        .unwrap_or(old)
+}
+
+pub fn nonzero_ui_many<T: NonZeroNum + Reflect>(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> bool {
+    let options = options
+        .downcast_ref::<NumberOptions<T::Num>>()
+        .cloned()
+        .unwrap_or_default();
+
+    let same = iter_all_eq(
+        values
+            .iter_mut()
+            .map(|value| projector(*value).try_downcast_ref::<T>().unwrap().get()),
+    )
+    .map(|value| from_base_unit(value.to_f64(), &options));
+
+    change_slider(ui, id, same, |expression| {
+        for value in values.iter_mut() {
+            let value = projector(*value)
+                .try_downcast_mut::<T>()
+                .expect("non-fully-reflected value passed to nonzero_ui_many");
+            let new = apply_expression(expression, value.get().to_f64(), &options);
+            *value = nonzero_or_skip_zero(*value, T::Num::from_f64(new));
+        }
+    })
+}
+
+/// `i128` and `u128` don't fit into the `f64` of a [`DragValue`], so they are edited as text.
+macro_rules! impl_wide_int {
+    ($($ty:ty),*) => {
+        $(
+            impl InspectorPrimitive for $ty {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    id: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) -> bool {
+                    let options = options
+                        .downcast_ref::<NumberOptions<$ty>>()
+                        .cloned()
+                        .unwrap_or_default();
+                    wide_int_ui(self, &options, ui, id)
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    _: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) {
+                    let options = options
+                        .downcast_ref::<NumberOptions<$ty>>()
+                        .cloned()
+                        .unwrap_or_default();
+                    ui.label(
+                        RichText::new(format!("{}{}{}", options.prefix, self, options.suffix))
+                            .monospace(),
+                    );
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_int!(i128, u128);
+
+fn wide_int_ui<T: FromStr<Err: Display> + Display + Ord + Copy>(
+    value: &mut T,
+    options: &NumberOptions<T>,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> bool {
+    ui.horizontal(|ui| {
+        if !options.prefix.is_empty() {
+            ui.label(&options.prefix);
+        }
+        let mut changed = parse_text_ui(value, ui, id);
+        if !options.suffix.is_empty() {
+            ui.label(&options.suffix);
+        }
+
+        let mut clamped = *value;
+        if let Some(min) = options.min {
+            clamped = clamped.max(min);
+        }
+        if let Some(max) = options.max {
+            clamped = clamped.min(max);
+        }
+        if clamped != *value {
+            *value = clamped;
+            changed = true;
+        }
+        changed
+    })
+    .inner
+}
+
+/// Text field for values which are edited through their [`FromStr`] and [`Display`] impls.
+///
+/// While the field is focused, the typed text is kept and the value only updated when the text parses.
+fn parse_text_ui<T: FromStr<Err: Display> + Display>(
+    value: &mut T,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> bool {
+    let id = id.with("parse_text");
+    let mut text = ui
+        .data(|data| data.get_temp::<String>(id))
+        .unwrap_or_else(|| value.to_string());
+
+    let response = ui.add(egui::TextEdit::singleline(&mut text).id(id));
+
+    let mut changed = false;
+    match text.parse::<T>() {
+        Ok(parsed) if response.changed() => {
+            *value = parsed;
+            changed = true;
+        }
+        Ok(_) => {}
+        Err(error) => errors::invalid_value(ui, &error.to_string()),
+    }
+
+    if response.has_focus() {
+        ui.data_mut(|data| data.insert_temp(id, text));
+    } else {
+        ui.data_mut(|data| data.remove::<String>(id));
+    }
+
+    changed
+}
+
+macro_rules! impl_parse_text {
+    ($($ty:ty),*) => {
+        $(
+            impl InspectorPrimitive for $ty {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    _: &dyn Any,
+                    id: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) -> bool {
+                    parse_text_ui(self, ui, id)
+                }
+
+                fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
+                    ui.label(RichText::new(self.to_string()).monospace());
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_text!(char, NonZeroI128, NonZeroU128, SocketAddr);
+
+/// [`OsString`]s are edited lossily, so invalid unicode is replaced once the value is changed.
+impl InspectorPrimitive for OsString {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let default = StringOptions::default();
+        let options = options.downcast_ref::<StringOptions>().unwrap_or(&default);
+
+        let mut text = self.to_string_lossy().into_owned();
+        let changed = string_ui(&mut text, ui, options, id);
+        if changed {
+            *self = OsString::from(text);
+        }
+
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let default = StringOptions::default();
+        let options = options.downcast_ref::<StringOptions>().unwrap_or(&default);
+        let text = self.to_string_lossy();
+        let multiline = text.contains('\n');
+        ui.add(text_edit(&mut text.as_ref(), options, multiline));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::number_parser;
+    use crate::inspector_options::std_options::{NumberDisplay, NumberOptions};
+
+    #[test]
+    fn number_fields_parse_relative_expressions() {
+        for display in [NumberDisplay::Drag, NumberDisplay::Slider] {
+            let options = NumberOptions::<f32> {
+                display,
+                max: Some(10.0),
+                ..Default::default()
+            };
+            let parse = number_parser(2.0, &options);
+            assert_eq!(parse("+=0.5"), Some(2.5));
+            assert_eq!(parse("*2"), Some(4.0));
+            assert_eq!(parse("1/4"), Some(0.25));
+            assert_eq!(parse("*100"), Some(10.0));
+            assert_eq!(parse("+="), None);
+        }
+    }
+}
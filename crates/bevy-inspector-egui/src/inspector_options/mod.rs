@@ -1,6 +1,6 @@
 //! Way of associating options to fields using [`struct@InspectorOptions`]
 
-use std::{any::Any, collections::HashMap};
+use std::{any::Any, borrow::Cow, collections::HashMap};
 
 use bevy_reflect::{FromType, TypeData};
 
@@ -22,6 +22,147 @@ pub enum Target {
 
 pub use bevy_inspector_egui_derive::InspectorOptions;
 
+/// Options which apply to a field independent of its type.
+///
+/// Set using the [derive macro](derive@InspectorOptions) on fields of any type:
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Player {
+///     #[inspector(label = "Max speed")]
+///     max_speed: f32,
+///     #[inspector(readonly)]
+///     distance_travelled: f32,
+///     #[inspector(hidden)]
+///     frames_since_jump: u32,
+///     #[inspector(group = "Physics", order = -1)]
+///     mass: f32,
+///     #[inspector(visible_if = Player::has_jetpack)]
+///     fuel: f32,
+/// }
+///
+/// impl Player {
+///     fn has_jetpack(&self) -> bool {
+///         self.mass > 100.0
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct FieldOptions {
+    /// Display the field, but don't allow editing it
+    pub readonly: bool,
+    /// Don't display the field at all
+    pub hidden: bool,
+    /// Label to display instead of the field name
+    pub label: Option<Cow<'static, str>>,
+    /// Collapsible group the field is displayed in
+    pub group: Option<Cow<'static, str>>,
+    /// Fields are displayed sorted by `order`, fields with the same order in declaration order
+    pub order: i32,
+    /// Only show the field if this returns `true` for the containing struct or enum.
+    /// When editing multiple values at once, the field is shown if it is visible for every value.
+    ///
+    /// The derive macro accepts a `fn(&Self) -> bool` and wraps it to downcast the value.
+    pub visible_if: Option<fn(&dyn Any) -> bool>,
+    /// Validates the field value, an error is displayed next to the field.
+    ///
+    /// The derive macro accepts a `fn(&FieldType) -> Result<(), String>` and wraps it to downcast the value.
+    pub validate: Option<fn(&dyn Any) -> Result<(), String>>,
+}
+
+/// Options controlling how the variant of an enum is selected.
+///
+/// Set using the [derive macro](derive@InspectorOptions) on the enum and its variants,
+/// or on fields of the enum type, which take precedence.
+/// Fields can only set them if the enum or one of its variants has an `#[inspector(..)]` attribute,
+/// an empty `#[inspector()]` is enough. Only then the derive implements [`InspectorOptionsType`] for the enum.
+///
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_inspector_egui::inspector_options::EnumDisplay;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// #[inspector(display = EnumDisplay::Segmented)]
+/// enum Quality {
+///     #[inspector(label = "Potato")]
+///     Low,
+///     #[default]
+///     Medium,
+///     High,
+///     #[inspector(hidden)]
+///     Debug,
+/// }
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Settings {
+///     #[inspector(display = EnumDisplay::Radio, hidden_variants = ["High"])]
+///     shadows: Quality,
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct EnumOptions {
+    /// How the variants are displayed, by default in a combo box
+    pub display: Option<EnumDisplay>,
+    /// Names of variants which can't be selected
+    pub hidden_variants: Vec<&'static str>,
+    /// Labels displayed instead of the variant names, as `(variant_name, label)`
+    pub variant_labels: Vec<(&'static str, &'static str)>,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EnumDisplay {
+    #[default]
+    ComboBox,
+    /// Radio buttons, for enums consisting of only unit variants
+    Radio,
+    /// A row of toggle buttons, for enums consisting of only unit variants
+    Segmented,
+}
+
+impl EnumOptions {
+    pub fn is_hidden(&self, variant_name: &str) -> bool {
+        self.hidden_variants.contains(&variant_name)
+    }
+
+    pub fn label<'a>(&self, variant_name: &'a str) -> &'a str {
+        self.variant_labels
+            .iter()
+            .find(|(name, _)| *name == variant_name)
+            .map_or(variant_name, |(_, label)| label)
+    }
+
+    /// Combines these options with `fallback`, preferring the ones set in `self`
+    pub fn merged_with(&self, fallback: Option<&EnumOptions>) -> EnumOptions {
+        let Some(fallback) = fallback else {
+            return self.clone();
+        };
+        EnumOptions {
+            display: self.display.or(fallback.display),
+            hidden_variants: self
+                .hidden_variants
+                .iter()
+                .chain(&fallback.hidden_variants)
+                .copied()
+                .collect(),
+            variant_labels: self
+                .variant_labels
+                .iter()
+                .chain(&fallback.variant_labels)
+                .copied()
+                .collect(),
+        }
+    }
+}
+
 /// Map of [`Target`]s to arbitrary [`TypeData`] used to control how the value is displayed, e.g. [`NumberOptions`](crate::inspector_options::std_options::NumberOptions).
 ///
 /// Comes with a [derive macro](derive@InspectorOptions), which generates a `FromType<T> for InspectorOptions` impl:
@@ -49,6 +190,8 @@ pub use bevy_inspector_egui_derive::InspectorOptions;
 #[derive(Default)]
 pub struct InspectorOptions {
     options: HashMap<Target, Box<dyn TypeData>>,
+    fields: HashMap<Target, FieldOptions>,
+    enum_options: Option<EnumOptions>,
 }
 
 impl std::fmt::Debug for InspectorOptions {
@@ -57,6 +200,12 @@ impl std::fmt::Debug for InspectorOptions {
         for entry in self.options.keys() {
             options.field(&format!("{entry:?}"), &"..");
         }
+        for (entry, field_options) in &self.fields {
+            options.field(&format!("{entry:?}"), field_options);
+        }
+        if let Some(enum_options) = &self.enum_options {
+            options.field("enum_options", enum_options);
+        }
         options.finish()
     }
 }
@@ -69,6 +218,8 @@ impl Clone for InspectorOptions {
                 .iter()
                 .map(|(target, data)| (*target, TypeData::clone_type_data(&**data)))
                 .collect(),
+            fields: self.fields.clone(),
+            enum_options: self.enum_options.clone(),
         }
     }
 }
@@ -90,6 +241,21 @@ impl InspectorOptions {
     pub fn iter(&self) -> impl Iterator<Item = (Target, &dyn TypeData)> + '_ {
         self.options.iter().map(|(target, data)| (*target, &**data))
     }
+
+    pub fn field_options(&self, target: Target) -> Option<&FieldOptions> {
+        self.fields.get(&target)
+    }
+    pub fn field_options_mut(&mut self, target: Target) -> &mut FieldOptions {
+        self.fields.entry(target).or_default()
+    }
+
+    /// Options of the enum these options belong to
+    pub fn enum_options(&self) -> Option<&EnumOptions> {
+        self.enum_options.as_ref()
+    }
+    pub fn enum_options_mut(&mut self) -> &mut EnumOptions {
+        self.enum_options.get_or_insert_default()
+    }
 }
 
 /// Wrapper of [`struct@InspectorOptions`] to be stored in the [`TypeRegistry`](bevy_reflect::TypeRegistry)
@@ -105,6 +271,56 @@ where
     }
 }
 
+/// Validation of values edited in the inspector, registered using `#[reflect(InspectorValidate)]`.
+///
+/// After drawing a value with a [`ReflectInspectorValidate`], the inspector displays the error next to it if the value is invalid.
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect)]
+/// #[reflect(InspectorValidate)]
+/// struct Range {
+///     min: f32,
+///     max: f32,
+/// }
+///
+/// impl InspectorValidate for Range {
+///     fn validate(&self) -> Result<(), String> {
+///         if self.min > self.max {
+///             return Err("min must not be greater than max".into());
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait InspectorValidate {
+    fn validate(&self) -> Result<(), String>;
+}
+
+/// [`TypeData`] for types implementing [`InspectorValidate`]
+#[derive(Clone)]
+pub struct ReflectInspectorValidate {
+    validate: fn(&dyn Any) -> Result<(), String>,
+}
+
+impl ReflectInspectorValidate {
+    /// Creates the type data from a function validating a value, which should succeed for values of other types.
+    pub fn new(validate: fn(&dyn Any) -> Result<(), String>) -> Self {
+        ReflectInspectorValidate { validate }
+    }
+
+    pub fn validate(&self, value: &dyn Any) -> Result<(), String> {
+        (self.validate)(value)
+    }
+}
+
+impl<T: InspectorValidate + Any> FromType<T> for ReflectInspectorValidate {
+    fn from_type() -> Self {
+        ReflectInspectorValidate::new(|value| value.downcast_ref::<T>().map_or(Ok(()), T::validate))
+    }
+}
+
 /// Helper trait for the [`derive@InspectorOptions`] macro.
 ///
 /// ```skip
@@ -1,5 +1,19 @@
 use bevy_ecs::entity::Entity;
-use std::collections::VecDeque;
+use bevy_math::EulerRot;
+use bevy_reflect::TypeData;
+use smallvec::SmallVec;
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::VecDeque,
+    ffi::OsString,
+    num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize, NonZeroU8, NonZeroU16,
+        NonZeroU32, NonZeroU64, NonZeroUsize, Saturating, Wrapping,
+    },
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
 
 use crate::InspectorOptions;
 
@@ -29,6 +43,18 @@ macro_rules! impl_options_defer_generic {
         }
     };
 }
+macro_rules! impl_options_list {
+    ($name:ident < $generic:ident >) => {
+        impl<$generic: InspectorOptionsType> InspectorOptionsType for $name<$generic> {
+            type DeriveOptions = ListOptions<<$generic as InspectorOptionsType>::DeriveOptions>;
+            type Options = ListOptions;
+
+            fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+                options.map(|element| ErasedOptions::new($generic::options_from_derive(element)))
+            }
+        }
+    };
+}
 
 #[derive(Clone)]
 #[non_exhaustive]
@@ -39,6 +65,19 @@ pub struct NumberOptions<T> {
     pub prefix: String,
     pub suffix: String,
     pub display: NumberDisplay,
+    /// Start of the slider range, if it should differ from `min`. Smaller values can still be typed in.
+    pub soft_min: Option<T>,
+    /// End of the slider range, if it should differ from `max`. Larger values can still be typed in.
+    pub soft_max: Option<T>,
+    /// Use a logarithmic slider, and drag proportionally to the magnitude of the value
+    pub logarithmic: bool,
+    /// Always display this many decimals
+    pub decimals: Option<usize>,
+    /// Snap edited values to multiples of this increment
+    pub step: Option<f64>,
+    /// Edit the value in this unit while storing it in the unit's base unit (radians or meters).
+    /// `min`, `max`, `soft_min`, `soft_max` and `step` are given in the base unit, `speed` in the displayed unit.
+    pub unit: Option<Unit>,
 }
 
 impl<T> Default for NumberOptions<T> {
@@ -50,6 +89,12 @@ impl<T> Default for NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            soft_min: None,
+            soft_max: None,
+            logarithmic: false,
+            decimals: None,
+            step: None,
+            unit: None,
         }
     }
 }
@@ -71,6 +116,7 @@ impl<T> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            ..Default::default()
         }
     }
     pub fn at_least(min: T) -> NumberOptions<T> {
@@ -81,6 +127,7 @@ impl<T> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            ..Default::default()
         }
     }
 
@@ -92,11 +139,17 @@ impl<T> NumberOptions<T> {
         NumberOptions {
             #[allow(clippy::redundant_closure)] // false positive
             min: self.min.as_ref().map(|min| f(min)),
+            soft_min: self.soft_min.as_ref().map(&f),
+            soft_max: self.soft_max.as_ref().map(&f),
             max: self.max.as_ref().map(f),
             speed: self.speed,
             prefix: self.prefix.clone(),
             suffix: self.suffix.clone(),
             display: NumberDisplay::default(),
+            logarithmic: self.logarithmic,
+            decimals: self.decimals,
+            step: self.step,
+            unit: self.unit,
         }
     }
 }
@@ -109,6 +162,7 @@ impl<T: egui::emath::Numeric> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            ..Default::default()
         }
     }
 
@@ -120,10 +174,101 @@ impl<T: egui::emath::Numeric> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            ..Default::default()
         }
     }
 }
 
+/// The unit a number is displayed in, see [`NumberOptions::unit`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Unit {
+    Angle(Angle),
+    Length(Length),
+}
+
+/// Angle units, stored in radians
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Angle {
+    Radians,
+    Degrees,
+    Turns,
+}
+
+/// Length units, stored in meters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Length {
+    Millimeters,
+    Centimeters,
+    Meters,
+    Kilometers,
+    Inches,
+    Feet,
+}
+
+impl Unit {
+    /// How many base units one displayed unit is
+    pub fn factor(self) -> f64 {
+        match self {
+            Unit::Angle(Angle::Radians) => 1.0,
+            Unit::Angle(Angle::Degrees) => std::f64::consts::PI / 180.0,
+            Unit::Angle(Angle::Turns) => std::f64::consts::TAU,
+            Unit::Length(Length::Millimeters) => 0.001,
+            Unit::Length(Length::Centimeters) => 0.01,
+            Unit::Length(Length::Meters) => 1.0,
+            Unit::Length(Length::Kilometers) => 1000.0,
+            Unit::Length(Length::Inches) => 0.0254,
+            Unit::Length(Length::Feet) => 0.3048,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Unit::Angle(Angle::Radians) => " rad",
+            Unit::Angle(Angle::Degrees) => "°",
+            Unit::Angle(Angle::Turns) => " turns",
+            Unit::Length(Length::Millimeters) => " mm",
+            Unit::Length(Length::Centimeters) => " cm",
+            Unit::Length(Length::Meters) => " m",
+            Unit::Length(Length::Kilometers) => " km",
+            Unit::Length(Length::Inches) => " in",
+            Unit::Length(Length::Feet) => " ft",
+        }
+    }
+
+    /// Converts a stored value into this unit
+    pub fn from_base(self, value: f64) -> f64 {
+        value / self.factor()
+    }
+
+    /// Converts a value in this unit into the stored base unit
+    pub fn to_base(self, value: f64) -> f64 {
+        value * self.factor()
+    }
+}
+
+impl From<Angle> for Unit {
+    fn from(angle: Angle) -> Self {
+        Unit::Angle(angle)
+    }
+}
+impl From<Length> for Unit {
+    fn from(length: Length) -> Self {
+        Unit::Length(length)
+    }
+}
+// allows `#[inspector(unit = Angle::Degrees)]`
+impl From<Angle> for Option<Unit> {
+    fn from(angle: Angle) -> Self {
+        Some(Unit::Angle(angle))
+    }
+}
+impl From<Length> for Option<Unit> {
+    fn from(length: Length) -> Self {
+        Some(Unit::Length(length))
+    }
+}
+
 impl_options!(f32 => NumberOptions<f32>);
 impl_options!(f64 => NumberOptions<f64>);
 impl_options!(i8 => NumberOptions<i8>);
@@ -139,6 +284,19 @@ impl_options!(u64 => NumberOptions<u64>);
 impl_options!(u128 => NumberOptions<u128>);
 impl_options!(usize => NumberOptions<usize>);
 
+impl_options!(NonZeroI8 => NumberOptions<i8>);
+impl_options!(NonZeroI16 => NumberOptions<i16>);
+impl_options!(NonZeroI32 => NumberOptions<i32>);
+impl_options!(NonZeroI64 => NumberOptions<i64>);
+impl_options!(NonZeroIsize => NumberOptions<isize>);
+impl_options!(NonZeroU8 => NumberOptions<u8>);
+impl_options!(NonZeroU16 => NumberOptions<u16>);
+impl_options!(NonZeroU32 => NumberOptions<u32>);
+impl_options!(NonZeroU64 => NumberOptions<u64>);
+impl_options!(NonZeroUsize => NumberOptions<usize>);
+impl_options_defer_generic!(Wrapping<T>);
+impl_options_defer_generic!(Saturating<T>);
+
 #[non_exhaustive]
 pub struct RangeOptions<T: InspectorOptionsType> {
     pub start: T::Options,
@@ -182,8 +340,115 @@ impl<T: InspectorOptionsType + 'static> InspectorOptionsType for std::ops::Range
 
 #[derive(Default, Clone)]
 #[non_exhaustive]
+pub struct StringOptions {
+    /// Always show a multiline text edit, not just when the string contains a newline
+    pub multiline: bool,
+    /// Show a multiline text edit with a monospace font
+    pub code: bool,
+    /// Maximum number of characters that can be typed in
+    pub max_length: Option<usize>,
+    /// Text shown when the string is empty
+    pub hint_text: String,
+    /// Show the characters as dots
+    pub password: bool,
+    /// Suggested values shown in a dropdown while editing
+    pub suggestions: Suggestions,
+}
+
+/// Suggested values for [`StringOptions::suggestions`].
+///
+/// ```rust
+/// # use bevy_inspector_egui::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// use bevy_inspector_egui::inspector_options::std_options::Suggestions;
+///
+/// fn level_names() -> Vec<String> {
+///     vec!["intro".to_string(), "castle".to_string()]
+/// }
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Config {
+///     #[inspector(suggestions = ["debug", "info", "warn"])]
+///     log_level: String,
+///     #[inspector(suggestions = Suggestions::Fn(level_names))]
+///     level: String,
+/// }
+/// ```
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub enum Suggestions {
+    #[default]
+    None,
+    List(Vec<Cow<'static, str>>),
+    /// Called every frame while the field is shown
+    Fn(fn() -> Vec<String>),
+}
+
+impl Suggestions {
+    pub fn get(&self) -> Vec<Cow<'static, str>> {
+        match self {
+            Suggestions::None => Vec::new(),
+            Suggestions::List(list) => list.clone(),
+            Suggestions::Fn(f) => f().into_iter().map(Cow::Owned).collect(),
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        matches!(self, Suggestions::None)
+    }
+}
+
+impl<const N: usize> From<[&'static str; N]> for Suggestions {
+    fn from(list: [&'static str; N]) -> Self {
+        Suggestions::List(list.into_iter().map(Cow::Borrowed).collect())
+    }
+}
+impl From<Vec<String>> for Suggestions {
+    fn from(list: Vec<String>) -> Self {
+        Suggestions::List(list.into_iter().map(Cow::Owned).collect())
+    }
+}
+
+impl_options!(String => StringOptions);
+impl_options!(Cow<'static, str> => StringOptions);
+impl_options!(OsString => StringOptions);
+
+/// Options for [`Quat`](bevy_math::Quat).
+///
+/// ```rust
+/// # use bevy_inspector_egui::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// use bevy_inspector_egui::inspector_options::std_options::{Angle, QuatDisplay};
+/// use bevy_math::{EulerRot, Quat};
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Camera {
+///     #[inspector(euler_rot = EulerRot::ZXY, unit = Angle::Degrees)]
+///     rotation: Quat,
+///     #[inspector(display = QuatDisplay::Arcball)]
+///     light_direction: Quat,
+/// }
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
 pub struct QuatOptions {
     pub display: QuatDisplay,
+    /// Order of the angles in [`QuatDisplay::Euler`]
+    pub euler_rot: EulerRot,
+    /// Unit of the displayed angles. By default, euler angles are shown in radians and all other angles in degrees.
+    pub unit: Option<Angle>,
+}
+
+impl Default for QuatOptions {
+    fn default() -> Self {
+        Self {
+            display: QuatDisplay::default(),
+            euler_rot: EulerRot::XYZ,
+            unit: None,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Default)]
@@ -193,10 +458,91 @@ pub enum QuatDisplay {
     Euler,
     YawPitchRoll,
     AxisAngle,
+    /// A sphere which is dragged to rotate, without the gimbal lock of euler angles
+    Arcball,
 }
 
 impl_options!(bevy_math::Quat => QuatOptions);
 
+/// Options for matrices and transforms, which can be shown decomposed into translation, rotation and scale.
+///
+/// ```rust
+/// # use bevy_inspector_egui::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// use bevy_inspector_egui::inspector_options::std_options::MatrixDisplay;
+/// use bevy_math::Mat4;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Joint {
+///     #[inspector(display = MatrixDisplay::Decomposed)]
+///     inverse_bind_pose: Mat4,
+/// }
+/// ```
+#[derive(Default, Clone)]
+#[non_exhaustive]
+pub struct MatrixOptions {
+    /// Defaults to [`MatrixDisplay::Raw`] for matrices and [`MatrixDisplay::Decomposed`] for `GlobalTransform`
+    pub display: Option<MatrixDisplay>,
+    /// Options of the decomposed rotation. The rotation of 2d transforms only uses the `unit`.
+    pub rotation: QuatOptions,
+}
+
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub enum MatrixDisplay {
+    #[default]
+    Raw,
+    /// Translation, rotation and scale. Shear and projection can't be represented and are removed on edit.
+    Decomposed,
+}
+
+impl_options!(bevy_math::Mat4 => MatrixOptions);
+impl_options!(bevy_math::DMat4 => MatrixOptions);
+impl_options!(bevy_math::Affine2 => MatrixOptions);
+impl_options!(bevy_math::Affine3A => MatrixOptions);
+impl_options!(bevy_transform::components::GlobalTransform => MatrixOptions);
+
+/// Options for [`Color`](bevy_color::Color).
+///
+/// ```rust
+/// # use bevy_inspector_egui::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// use bevy_color::Color;
+/// use bevy_inspector_egui::inspector_options::std_options::ColorSpace;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Material {
+///     #[inspector(hide_alpha, space = ColorSpace::Hsv)]
+///     base_color: Color,
+///     #[inspector(hdr)]
+///     emissive: Color,
+/// }
+/// ```
+#[derive(Default, Clone)]
+#[non_exhaustive]
+pub struct ColorOptions {
+    /// Don't show or edit the alpha channel
+    pub hide_alpha: bool,
+    /// Allow components above `1.0`, edited as a color and an intensity multiplier.
+    ///
+    /// Colors that are already brighter than `1.0` always show the intensity.
+    pub hdr: bool,
+    /// Show the components of the color in this space next to the color picker
+    pub space: Option<ColorSpace>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColorSpace {
+    Srgb,
+    Linear,
+    Hsv,
+    Oklch,
+}
+
+impl_options!(bevy_color::Color => ColorOptions);
+
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct EntityOptions {
@@ -243,8 +589,160 @@ impl<T: InspectorOptionsType> InspectorOptionsType for Option<T> {
     }
 }
 
-impl_options_defer_generic!(Vec<T>);
-impl_options_defer_generic!(VecDeque<T>);
+/// Options for lists like `Vec<T>`, `VecDeque<T>` and `SmallVec<[T; N]>`.
+///
+/// When derived, the options of the element type can be used next to the list options,
+/// as [`ListOptions`] dereferences to the `element` options.
+///
+/// ```rust
+/// # use bevy_inspector_egui::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// fn waypoint_label(index: usize, _: &dyn std::any::Any) -> String {
+///     format!("Waypoint {}", index + 1)
+/// }
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Path {
+///     #[inspector(min_len = 2, allow_reorder = false, element_label = waypoint_label)]
+///     waypoints: Vec<f32>,
+///     #[inspector(fixed_len = 3, min = 0.0)]
+///     weights: Vec<f32>,
+/// }
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct ListOptions<T = ErasedOptions> {
+    /// Don't allow adding or removing elements
+    pub fixed_len: Option<usize>,
+    pub min_len: Option<usize>,
+    pub max_len: Option<usize>,
+    pub allow_reorder: bool,
+    pub allow_add: bool,
+    pub allow_remove: bool,
+    /// Label shown for an element instead of its index
+    pub element_label: ElementLabel,
+    /// Options passed to each element
+    pub element: T,
+}
+
+impl<T: Default> Default for ListOptions<T> {
+    fn default() -> Self {
+        Self {
+            fixed_len: None,
+            min_len: None,
+            max_len: None,
+            allow_reorder: true,
+            allow_add: true,
+            allow_remove: true,
+            element_label: ElementLabel::default(),
+            element: T::default(),
+        }
+    }
+}
+
+impl<T> ListOptions<T> {
+    pub fn can_add(&self, len: usize) -> bool {
+        self.allow_add && self.fixed_len.is_none() && self.max_len.is_none_or(|max| len < max)
+    }
+
+    pub fn can_remove(&self, len: usize) -> bool {
+        self.allow_remove && self.fixed_len.is_none() && self.min_len.is_none_or(|min| len > min)
+    }
+
+    /// Whether a list of `len` elements satisfies `fixed_len`, `min_len` and `max_len`
+    pub fn allows_len(&self, len: usize) -> bool {
+        self.fixed_len.is_none_or(|fixed| len == fixed)
+            && self.min_len.is_none_or(|min| len >= min)
+            && self.max_len.is_none_or(|max| len <= max)
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ListOptions<U> {
+        ListOptions {
+            fixed_len: self.fixed_len,
+            min_len: self.min_len,
+            max_len: self.max_len,
+            allow_reorder: self.allow_reorder,
+            allow_add: self.allow_add,
+            allow_remove: self.allow_remove,
+            element_label: self.element_label,
+            element: f(self.element),
+        }
+    }
+}
+
+impl<T> Deref for ListOptions<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+impl<T> DerefMut for ListOptions<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+/// Function computing the label of a list element from its index and value, see [`ListOptions::element_label`]
+#[derive(Clone, Default)]
+pub struct ElementLabel(Option<Arc<dyn Fn(usize, &dyn Any) -> String + Send + Sync>>);
+
+impl ElementLabel {
+    pub fn label(&self, index: usize, element: &dyn Any) -> Option<String> {
+        self.0.as_ref().map(|f| f(index, element))
+    }
+}
+
+impl<F: Fn(usize, &dyn Any) -> String + Send + Sync + 'static> From<F> for ElementLabel {
+    fn from(f: F) -> Self {
+        ElementLabel(Some(Arc::new(f)))
+    }
+}
+
+/// Type erased options, e.g. the options of the elements of a [`ListOptions`]
+pub struct ErasedOptions(Box<dyn TypeData>);
+
+impl ErasedOptions {
+    pub fn new<T: TypeData>(options: T) -> Self {
+        ErasedOptions(Box::new(options))
+    }
+
+    pub fn as_any(&self) -> &dyn Any {
+        (*self.0).as_any()
+    }
+
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+}
+
+impl Default for ErasedOptions {
+    fn default() -> Self {
+        ErasedOptions::new(())
+    }
+}
+
+impl Clone for ErasedOptions {
+    fn clone(&self) -> Self {
+        ErasedOptions(self.0.clone_type_data())
+    }
+}
+
+impl_options_list!(Vec<T>);
+impl_options_list!(VecDeque<T>);
+
+impl<A: smallvec::Array> InspectorOptionsType for SmallVec<A>
+where
+    A::Item: InspectorOptionsType,
+{
+    type DeriveOptions = ListOptions<<A::Item as InspectorOptionsType>::DeriveOptions>;
+    type Options = ListOptions;
+
+    fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+        options.map(|element| ErasedOptions::new(A::Item::options_from_derive(element)))
+    }
+}
 
 impl<T: InspectorOptionsType, const N: usize> InspectorOptionsType for [T; N] {
     type DeriveOptions = T::DeriveOptions;
@@ -198,6 +198,9 @@ impl bevy_app::Plugin for DefaultInspectorConfigPlugin {
             .register_type::<core::ops::Range<f32>>()
             .register_type::<TypeId>();
 
+        app.init_resource::<bevy_inspector::history::InspectorHistory>()
+            .add_event::<bevy_inspector::history::InspectorEdit>();
+
         let type_registry = app.world().resource::<bevy_ecs::prelude::AppTypeRegistry>();
         let mut type_registry = type_registry.write();
 
@@ -221,4 +224,6 @@ pub mod prelude {
     // for `#[derive(Reflect)] #[reflect(InspectorOptions)]
     pub use crate::inspector_options::InspectorOptions;
     pub use crate::inspector_options::ReflectInspectorOptions;
+    // for `#[reflect(InspectorValidate)]`
+    pub use crate::inspector_options::{InspectorValidate, ReflectInspectorValidate};
 }
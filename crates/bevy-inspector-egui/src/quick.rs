@@ -97,6 +97,7 @@ fn world_inspector_ui(world: &mut World) {
         return;
     };
     let mut egui_context = egui_context.clone();
+    bevy_inspector::history::handle_shortcuts(world, egui_context.get_mut());
 
     egui::Window::new("World Inspector")
         .default_size(DEFAULT_SIZE)
@@ -192,6 +193,7 @@ fn inspector_ui<T: Resource + Reflect>(world: &mut World) {
         return;
     };
     let mut egui_context = egui_context.clone();
+    bevy_inspector::history::handle_shortcuts(world, egui_context.get_mut());
 
     egui::Window::new(pretty_type_name::<T>())
         .default_size((0., 0.))
@@ -366,6 +368,7 @@ fn asset_inspector_ui<A: Asset + Reflect>(world: &mut World) {
         return;
     };
     let mut egui_context = egui_context.clone();
+    bevy_inspector::history::handle_shortcuts(world, egui_context.get_mut());
 
     egui::Window::new(pretty_type_name::<A>())
         .default_size(DEFAULT_SIZE)
@@ -446,6 +449,7 @@ fn entity_query_ui<F: QueryFilter>(world: &mut World) {
         return;
     };
     let mut egui_context = egui_context.clone();
+    bevy_inspector::history::handle_shortcuts(world, egui_context.get_mut());
 
     egui::Window::new(pretty_type_name::<F>())
         .default_size(DEFAULT_SIZE)
@@ -89,3 +89,11 @@ pub fn no_multiedit(ui: &mut egui::Ui, type_name: &str) {
 
     ui.label(job);
 }
+
+pub fn invalid_ron(ui: &mut egui::Ui, error: &str) {
+    ui.colored_label(ui.visuals().error_fg_color, error);
+}
+
+pub fn invalid_value(ui: &mut egui::Ui, error: &str) {
+    ui.colored_label(ui.visuals().error_fg_color, format!("⚠ {error}"));
+}
@@ -48,7 +48,7 @@
 //!
 //!     // alternatively
 //!     // use crate::bevy_inspector::short_circuit;
-//!     // let mut env = InspectorUi::new(type_registry, &mut cx, Some(short_circuit::short_circuit), Some(short_circuit::short_circuit_readonly));
+//!     // let mut env = InspectorUi::with_short_circuit(type_registry, &mut cx, short_circuit::default_handlers());
 //!
 //!     let changed = env.ui_for_reflect(data.bypass_change_detection(), ui);
 //!     if changed {
@@ -62,23 +62,29 @@
 #[cfg(feature = "documentation")]
 use crate::egui_utils::show_docs;
 
+use crate::bevy_inspector::history::ValueSnapshot;
 use crate::inspector_egui_impls::{InspectorEguiImpl, iter_all_eq};
-use crate::inspector_options::{InspectorOptions, ReflectInspectorOptions, Target};
+use crate::inspector_options::{
+    EnumDisplay, EnumOptions, FieldOptions, InspectorOptions, ReflectInspectorOptions,
+    ReflectInspectorValidate, Target, std_options::ListOptions,
+};
 use crate::restricted_world_view::RestrictedWorldView;
 use crate::{
     egui_utils::{add_button, down_button, remove_button, up_button},
-    utils::pretty_type_name_str,
+    utils::{from_ron, to_ron},
 };
 use bevy_ecs::world::CommandQueue;
 use bevy_reflect::{
-    Array, DynamicEnum, DynamicTuple, DynamicVariant, Enum, EnumInfo, List, ListInfo, Map, Reflect,
-    ReflectMut, ReflectRef, Struct, StructInfo, Tuple, TupleInfo, TupleStruct, TupleStructInfo,
-    TypeInfo, TypeRegistry, VariantInfo, VariantType,
+    Access, Array, ArrayInfo, DynamicEnum, DynamicTuple, DynamicVariant, Enum, EnumInfo, List,
+    ListInfo, Map, MapInfo, ParsedPath, Reflect, ReflectDeserialize, ReflectMut, ReflectRef,
+    ReflectSerialize, Struct, StructInfo, Tuple, TupleInfo, TupleStruct, TupleStructInfo, TypeInfo,
+    TypeRegistration, TypeRegistry, VariantInfo, VariantType,
 };
 use bevy_reflect::{DynamicStruct, std_traits::ReflectDefault};
-use bevy_reflect::{OpaqueInfo, PartialReflect, Set, SetInfo};
+use bevy_reflect::{OpaqueInfo, PartialReflect, ReflectPath, Set, SetInfo};
 use egui::{Grid, WidgetText};
 use std::borrow::Cow;
+use std::sync::Arc;
 use std::{
     any::{Any, TypeId},
     borrow::Borrow,
@@ -166,18 +172,230 @@ pub type ShortCircuitFnMany = fn(
     projector: &dyn ProjectorReflect,
 ) -> Option<bool>;
 
+/// Boxed version of [`ShortCircuitFn`], which can capture state.
+pub type ShortCircuitClosure = dyn Fn(
+        &mut InspectorUi<'_, '_>,
+        &mut dyn PartialReflect,
+        &mut egui::Ui,
+        egui::Id,
+        &dyn Any,
+    ) -> Option<bool>
+    + Send
+    + Sync;
+/// Boxed version of [`ShortCircuitFnReadonly`], which can capture state.
+pub type ShortCircuitClosureReadonly = dyn Fn(
+        &mut InspectorUi<'_, '_>,
+        &dyn PartialReflect,
+        &mut egui::Ui,
+        egui::Id,
+        &dyn Any,
+    ) -> Option<()>
+    + Send
+    + Sync;
+/// Boxed version of [`ShortCircuitFnMany`], which can capture state.
+pub type ShortCircuitClosureMany = dyn Fn(
+        &mut InspectorUi<'_, '_>,
+        TypeId,
+        &str,
+        &mut egui::Ui,
+        egui::Id,
+        &dyn Any,
+        &mut [&mut dyn PartialReflect],
+        &dyn ProjectorReflect,
+    ) -> Option<bool>
+    + Send
+    + Sync;
+
+/// A single short circuiting handler, with optional functions for the mutable, readonly and multi-editing case.
+///
+/// ```rust
+/// use bevy_inspector_egui::reflect_inspector::ShortCircuitHandler;
+///
+/// let handler = ShortCircuitHandler::default()
+///     .readonly(|_env, value, ui, _id, _options| {
+///         let s = value.try_downcast_ref::<String>()?;
+///         ui.monospace(s);
+///         Some(())
+///     });
+/// ```
+#[derive(Clone, Default)]
+pub struct ShortCircuitHandler {
+    short_circuit: Option<Arc<ShortCircuitClosure>>,
+    short_circuit_readonly: Option<Arc<ShortCircuitClosureReadonly>>,
+    short_circuit_many: Option<Arc<ShortCircuitClosureMany>>,
+}
+
+impl ShortCircuitHandler {
+    /// Handler for mutable values, see [`ShortCircuitFn`].
+    pub fn new(
+        short_circuit: impl Fn(
+            &mut InspectorUi<'_, '_>,
+            &mut dyn PartialReflect,
+            &mut egui::Ui,
+            egui::Id,
+            &dyn Any,
+        ) -> Option<bool>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        ShortCircuitHandler {
+            short_circuit: Some(Arc::new(short_circuit)),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the handler for readonly values, see [`ShortCircuitFnReadonly`].
+    pub fn readonly(
+        mut self,
+        short_circuit_readonly: impl Fn(
+            &mut InspectorUi<'_, '_>,
+            &dyn PartialReflect,
+            &mut egui::Ui,
+            egui::Id,
+            &dyn Any,
+        ) -> Option<()>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.short_circuit_readonly = Some(Arc::new(short_circuit_readonly));
+        self
+    }
+
+    /// Sets the handler for editing multiple values at once, see [`ShortCircuitFnMany`].
+    pub fn many(
+        mut self,
+        short_circuit_many: impl Fn(
+            &mut InspectorUi<'_, '_>,
+            TypeId,
+            &str,
+            &mut egui::Ui,
+            egui::Id,
+            &dyn Any,
+            &mut [&mut dyn PartialReflect],
+            &dyn ProjectorReflect,
+        ) -> Option<bool>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.short_circuit_many = Some(Arc::new(short_circuit_many));
+        self
+    }
+
+    /// Creates a handler from plain function pointers, as accepted by [`InspectorUi::new`].
+    pub fn from_fns(
+        short_circuit: Option<ShortCircuitFn>,
+        short_circuit_readonly: Option<ShortCircuitFnReadonly>,
+        short_circuit_many: Option<ShortCircuitFnMany>,
+    ) -> Self {
+        ShortCircuitHandler {
+            short_circuit: short_circuit.map(|f| Arc::new(f) as Arc<ShortCircuitClosure>),
+            short_circuit_readonly: short_circuit_readonly
+                .map(|f| Arc::new(f) as Arc<ShortCircuitClosureReadonly>),
+            short_circuit_many: short_circuit_many
+                .map(|f| Arc::new(f) as Arc<ShortCircuitClosureMany>),
+        }
+    }
+}
+
+/// Chain of [`ShortCircuitHandler`]s which get executed for every field recursively, in order of descending priority.
+///
+/// The first handler returning `Some` wins, and regular traversal is skipped.
+/// Cloning is cheap, the handlers are reference counted.
+///
+/// Inserted as a resource, they are used by [`InspectorUi::for_bevy`], see [`short_circuit::add_handler`](crate::bevy_inspector::short_circuit::add_handler).
+///
+/// ```rust
+/// use bevy_inspector_egui::reflect_inspector::{ShortCircuitHandler, ShortCircuitHandlers};
+///
+/// let handlers = ShortCircuitHandlers::default().with(
+///     10,
+///     ShortCircuitHandler::new(|_env, value, ui, _id, _options| {
+///         let value = value.try_downcast_mut::<u8>()?;
+///         Some(ui.add(egui::Slider::new(value, 0..=255)).changed())
+///     }),
+/// );
+/// ```
+#[derive(Clone, Default, bevy_ecs::prelude::Resource)]
+pub struct ShortCircuitHandlers {
+    handlers: Arc<Vec<(i32, ShortCircuitHandler)>>,
+}
+
+impl ShortCircuitHandlers {
+    /// Adds a handler. Handlers with a higher priority run first, handlers with the same priority in the order they were added.
+    pub fn add(&mut self, priority: i32, handler: ShortCircuitHandler) -> &mut Self {
+        let handlers = Arc::make_mut(&mut self.handlers);
+        let index = handlers.partition_point(|(p, _)| *p >= priority);
+        handlers.insert(index, (priority, handler));
+        self
+    }
+
+    /// Builder-style version of [`ShortCircuitHandlers::add`]
+    pub fn with(mut self, priority: i32, handler: ShortCircuitHandler) -> Self {
+        self.add(priority, handler);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    fn short_circuit(
+        &self,
+        env: &mut InspectorUi<'_, '_>,
+        value: &mut dyn PartialReflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) -> Option<bool> {
+        self.handlers
+            .iter()
+            .find_map(|(_, handler)| handler.short_circuit.as_ref()?(env, value, ui, id, options))
+    }
+
+    fn short_circuit_readonly(
+        &self,
+        env: &mut InspectorUi<'_, '_>,
+        value: &dyn PartialReflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) -> Option<()> {
+        self.handlers.iter().find_map(|(_, handler)| {
+            handler.short_circuit_readonly.as_ref()?(env, value, ui, id, options)
+        })
+    }
+
+    fn short_circuit_many(
+        &self,
+        env: &mut InspectorUi<'_, '_>,
+        type_id: TypeId,
+        type_name: &str,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        values: &mut [&mut dyn PartialReflect],
+        projector: &dyn ProjectorReflect,
+    ) -> Option<bool> {
+        self.handlers.iter().find_map(|(_, handler)| {
+            handler.short_circuit_many.as_ref()?(
+                env, type_id, type_name, ui, id, options, values, projector,
+            )
+        })
+    }
+}
+
 pub struct InspectorUi<'a, 'c> {
     /// Reference to the [`TypeRegistry`]
     pub type_registry: &'a TypeRegistry,
     /// [`Context`] with additional data that can be used to display values
     pub context: &'a mut Context<'c>,
 
-    /// Function which will be executed for every field recursively, which can be used to skip regular traversal.
+    /// Handlers which will be executed for every field recursively, which can be used to skip regular traversal.
     /// This can be used to recognize `Handle<T>` types and display them as their actual value instead.
-    pub short_circuit: ShortCircuitFn,
-    /// Same as [`short_circuit`](InspectorUi::short_circuit), but for read only usage.
-    pub short_circuit_readonly: ShortCircuitFnReadonly,
-    pub short_circuit_many: ShortCircuitFnMany,
+    pub short_circuit: ShortCircuitHandlers,
 }
 
 impl<'a, 'c> InspectorUi<'a, 'c> {
@@ -187,13 +405,29 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
         short_circuit: Option<ShortCircuitFn>,
         short_circuit_readonly: Option<ShortCircuitFnReadonly>,
         short_circuit_many: Option<ShortCircuitFnMany>,
+    ) -> Self {
+        let handler = ShortCircuitHandler::from_fns(
+            short_circuit,
+            short_circuit_readonly,
+            short_circuit_many,
+        );
+        InspectorUi::with_short_circuit(
+            type_registry,
+            context,
+            ShortCircuitHandlers::default().with(0, handler),
+        )
+    }
+
+    /// [`InspectorUi`] with a custom chain of [`ShortCircuitHandlers`]
+    pub fn with_short_circuit(
+        type_registry: &'a TypeRegistry,
+        context: &'a mut Context<'c>,
+        short_circuit: ShortCircuitHandlers,
     ) -> Self {
         Self {
             type_registry,
             context,
-            short_circuit: short_circuit.unwrap_or(|_, _, _, _, _| None),
-            short_circuit_readonly: short_circuit_readonly.unwrap_or(|_, _, _, _, _| None),
-            short_circuit_many: short_circuit_many.unwrap_or(|_, _, _, _, _, _, _, _| None),
+            short_circuit,
         }
     }
 
@@ -201,7 +435,7 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
         type_registry: &'a TypeRegistry,
         context: &'a mut Context<'c>,
     ) -> Self {
-        InspectorUi::new(type_registry, context, None, None, None)
+        InspectorUi::with_short_circuit(type_registry, context, ShortCircuitHandlers::default())
     }
 }
 
@@ -221,12 +455,35 @@ impl InspectorUi<'_, '_> {
     /// The options can be [`struct@InspectorOptions`] for structs or enums with nested options for their fields,
     /// or other structs like [`NumberOptions`](crate::inspector_options::std_options::NumberOptions) which are interpreted
     /// by leaf types like `f32` or `Vec3`,
+    ///
+    /// If the type has a [`ReflectInspectorValidate`] and the value is invalid, the error is displayed after the value.
     pub fn ui_for_reflect_with_options(
         &mut self,
         value: &mut dyn PartialReflect,
         ui: &mut egui::Ui,
         id: egui::Id,
         options: &dyn Any,
+    ) -> bool {
+        let changed = self.ui_for_reflect_unvalidated(value, ui, id, options);
+
+        if let Some(value) = value.try_as_reflect()
+            && let Some(validate) = self
+                .type_registry
+                .get_type_data::<ReflectInspectorValidate>(value.as_any().type_id())
+            && let Err(error) = validate.validate(value.as_any())
+        {
+            errors::invalid_value(ui, &error);
+        }
+
+        changed
+    }
+
+    fn ui_for_reflect_unvalidated(
+        &mut self,
+        value: &mut dyn PartialReflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
     ) -> bool {
         let mut options = options;
         if options.is::<()>()
@@ -238,16 +495,23 @@ impl InspectorUi<'_, '_> {
             options = &data.0;
         }
 
-        if let Some(reflected) = value.try_as_reflect_mut()
+        if let Some(reflected) = value.try_as_reflect()
             && let Some(s) = self
                 .type_registry
                 .get_type_data::<InspectorEguiImpl>(reflected.reflect_type_info().type_id())
-            && let Some(value) = value.try_as_reflect_mut()
         {
-            return s.execute(value.as_any_mut(), ui, options, id, self.reborrow());
+            let snapshot = ValueSnapshot::take(ui, value);
+            let reflected = value.try_as_reflect_mut().unwrap();
+            let changed = s.execute(reflected.as_any_mut(), ui, options, id, self.reborrow());
+            snapshot.record(changed);
+            return changed;
         }
 
-        if let Some(changed) = (self.short_circuit)(self, value, ui, id, options) {
+        if let Some(changed) = self
+            .short_circuit
+            .clone()
+            .short_circuit(self, value, ui, id, options)
+        {
             return changed;
         }
 
@@ -301,7 +565,11 @@ impl InspectorUi<'_, '_> {
             return;
         }
 
-        if let Some(()) = (self.short_circuit_readonly)(self, value, ui, id, options) {
+        if let Some(()) = self
+            .short_circuit
+            .clone()
+            .short_circuit_readonly(self, value, ui, id, options)
+        {
             return;
         }
 
@@ -368,8 +636,10 @@ impl InspectorUi<'_, '_> {
             return s.execute_many(ui, options, id, self.reborrow(), values, projector);
         }
 
-        if let Some(changed) =
-            (self.short_circuit_many)(self, type_id, name, ui, id, options, values, projector)
+        if let Some(changed) = self
+            .short_circuit
+            .clone()
+            .short_circuit_many(self, type_id, name, ui, id, options, values, projector)
         {
             return changed;
         }
@@ -386,15 +656,13 @@ impl InspectorUi<'_, '_> {
             }
             TypeInfo::List(info) => self.ui_for_list_many(info, ui, id, options, values, projector),
             TypeInfo::Array(info) => {
-                errors::no_multiedit(ui, &pretty_type_name_str(info.type_path()));
-                false
-            }
-            TypeInfo::Map(info) => {
-                errors::no_multiedit(ui, &pretty_type_name_str(info.type_path()));
-                false
+                self.ui_for_array_many(info, ui, id, options, values, projector)
             }
+            TypeInfo::Map(info) => self.ui_for_reflect_map_many(info, ui, id, values, projector),
             TypeInfo::Enum(info) => self.ui_for_enum_many(info, ui, id, options, values, projector),
-            TypeInfo::Opaque(info) => self.ui_for_value_many(info, ui, id, options),
+            TypeInfo::Opaque(info) => {
+                self.ui_for_value_many(info, ui, id, options, values, projector)
+            }
             TypeInfo::Set(info) => self.ui_for_set_many(info, ui, id, options, values, projector),
         }
     }
@@ -423,20 +691,104 @@ fn ui_for_empty_collection(ui: &mut egui::Ui, label: impl Into<WidgetText>) -> b
     add
 }
 
-fn ui_for_empty_list(ui: &mut egui::Ui) -> bool {
-    ui_for_empty_collection(ui, "(Empty List)")
+fn ui_for_empty_list(ui: &mut egui::Ui, list_options: Option<&ListOptions>) -> bool {
+    if list_options.is_none_or(|list_options| list_options.can_add(0)) {
+        ui_for_empty_collection(ui, "(Empty List)")
+    } else {
+        ui.vertical_centered(|ui| ui.label("(Empty List)"));
+        false
+    }
+}
+
+/// Keys present in every map, which can be edited together, and whether all maps have exactly these keys.
+fn common_map_keys<'a>(
+    maps: impl IntoIterator<Item = &'a dyn Map>,
+) -> (Vec<Box<dyn PartialReflect>>, bool) {
+    let maps: Vec<&dyn Map> = maps.into_iter().collect();
+    let Some(first) = maps.first() else {
+        return (Vec::new(), true);
+    };
+    let mut keys: Vec<Box<dyn PartialReflect>> = first
+        .iter()
+        .map(|(key, _)| {
+            key.reflect_clone()
+                .map(|key| key.into_partial_reflect())
+                .unwrap_or_else(|_| key.to_dynamic())
+        })
+        .collect();
+    for map in &maps {
+        keys.retain(|key| map.get(key.as_ref()).is_some());
+    }
+    let same_keys = maps.iter().all(|map| map.len() == keys.len());
+    (keys, same_keys)
+}
+
+/// Splits the options of a list into its [`ListOptions`] and the options passed to the elements.
+///
+/// Options which aren't [`ListOptions`] are passed to the elements directly.
+fn split_list_options(options: &dyn Any) -> (Option<&ListOptions>, &dyn Any) {
+    match options.downcast_ref::<ListOptions>() {
+        Some(list_options) => (Some(list_options), list_options.element.as_any()),
+        None => (None, options),
+    }
+}
+
+/// Checks that a new list `value` has a length allowed by the [`ListOptions`] in `options`.
+fn check_list_len(options: &dyn Any, value: &dyn PartialReflect) -> Result<(), String> {
+    let (Some(list_options), ReflectRef::List(list)) =
+        (split_list_options(options).0, value.reflect_ref())
+    else {
+        return Ok(());
+    };
+    if list_options.allows_len(list.len()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "A list of {} elements isn't allowed here",
+            list.len()
+        ))
+    }
 }
 
-fn ui_for_list_controls(ui: &mut egui::Ui, index: usize, len: usize) -> Option<ListOp> {
+fn list_element_label(
+    list_options: Option<&ListOptions>,
+    index: usize,
+    element: &dyn PartialReflect,
+) -> String {
+    list_options
+        .zip(element.try_as_reflect())
+        .and_then(|(list_options, element)| {
+            list_options.element_label.label(index, element.as_any())
+        })
+        .unwrap_or_else(|| index.to_string())
+}
+
+fn ui_for_list_controls(
+    ui: &mut egui::Ui,
+    index: usize,
+    len: usize,
+    list_options: Option<&ListOptions>,
+) -> Option<ListOp> {
     use ListOp::*;
     let mut op = None;
+    let (can_add, can_remove, allow_reorder) = match list_options {
+        Some(list_options) => (
+            list_options.can_add(len),
+            list_options.can_remove(len),
+            list_options.allow_reorder,
+        ),
+        None => (true, true, true),
+    };
     ui.horizontal_top(|ui| {
-        if add_button(ui).on_hover_text("Add element").clicked() {
+        if can_add && add_button(ui).on_hover_text("Add element").clicked() {
             op = Some(AddElement(index));
         }
-        if remove_button(ui).on_hover_text("Remove element").clicked() {
+        if can_remove && remove_button(ui).on_hover_text("Remove element").clicked() {
             op = Some(RemoveElement(index));
         }
+        if !allow_reorder {
+            return;
+        }
         let up_enabled = index > 0;
         ui.add_enabled_ui(up_enabled, |ui| {
             if up_button(ui).on_hover_text("Move element up").clicked() {
@@ -457,6 +809,80 @@ fn ui_for_empty_set(ui: &mut egui::Ui) {
     ui.vertical_centered(|ui| ui.label("(Empty Set)"));
 }
 
+/// Collections longer than this are split into pages
+const PAGE_SIZE: usize = 100;
+/// Maximum height of a page, in rows
+const PAGE_VISIBLE_ROWS: f32 = 20.0;
+
+/// Lays out the elements of a collection of length `len` by calling `add_rows` with the range of elements to show.
+///
+/// Short collections are shown in full. Longer ones get an "N elements" summary and paging controls,
+/// and only the rows of the current page which are scrolled into view are laid out.
+fn ui_for_long_collection(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    len: usize,
+    add_rows: impl FnOnce(&mut egui::Ui, std::ops::Range<usize>),
+) {
+    if len <= PAGE_SIZE {
+        add_rows(ui, 0..len);
+        return;
+    }
+
+    let page_id = id.with("page");
+    let row_height_id = id.with("row_height");
+    let page_count = len.div_ceil(PAGE_SIZE);
+    let mut page = ui
+        .data(|data| data.get_temp::<usize>(page_id))
+        .unwrap_or(0)
+        .min(page_count - 1);
+
+    ui.horizontal(|ui| {
+        ui.label(format!("{len} elements"));
+        if ui.add_enabled(page > 0, egui::Button::new("⏴")).clicked() {
+            page -= 1;
+        }
+        let mut page_number = page + 1;
+        ui.add(
+            egui::DragValue::new(&mut page_number)
+                .range(1..=page_count)
+                .prefix("page ")
+                .suffix(format!(" / {page_count}")),
+        );
+        page = page_number.clamp(1, page_count) - 1;
+        if ui
+            .add_enabled(page + 1 < page_count, egui::Button::new("⏵"))
+            .clicked()
+        {
+            page += 1;
+        }
+    });
+    ui.data_mut(|data| data.insert_temp(page_id, page));
+
+    let page_start = page * PAGE_SIZE;
+    let page_len = PAGE_SIZE.min(len - page_start);
+
+    // rows are assumed to all have the height of the rows measured in the last frame
+    let row_height = ui
+        .data(|data| data.get_temp::<f32>(row_height_id))
+        .unwrap_or(ui.spacing().interact_size.y);
+    let spacing = ui.spacing().item_spacing.y;
+
+    egui::ScrollArea::vertical()
+        .id_salt(id.with(page))
+        .max_height((row_height + spacing) * PAGE_VISIBLE_ROWS)
+        .show_rows(ui, row_height, page_len, |ui, rows| {
+            let count = rows.len();
+            let top = ui.cursor().top();
+            add_rows(ui, page_start + rows.start..page_start + rows.end);
+
+            if count > 0 {
+                let measured = (ui.cursor().top() - top) / count as f32 - spacing;
+                ui.data_mut(|data| data.insert_temp(row_height_id, measured.max(1.0)));
+            }
+        });
+}
+
 struct MapDraftElement {
     key: Box<dyn PartialReflect>,
     value: Box<dyn PartialReflect>,
@@ -491,23 +917,42 @@ impl InspectorUi<'_, '_> {
         };
 
         let mut changed = false;
-        Grid::new(id).show(ui, |ui| {
-            for i in 0..value.field_len() {
-                let field_info = type_info.field_at(i).unwrap();
-
-                let _response = ui.label(field_info.name());
-                #[cfg(feature = "documentation")]
-                show_docs(_response, field_info.docs());
-
-                let field = value.field_at_mut(i).unwrap();
-                changed |= self.ui_for_reflect_with_options(
+        let groups = struct_field_groups(options, value.field_len(), |i| {
+            field_visible(field_options(options, Target::Field(i)), &*value)
+        });
+        let label = |i| struct_field_label(options, type_info, i);
+        show_field_groups(ui, id, groups, label, |ui, i| {
+            let field_info = type_info.field_at(i).unwrap();
+            let field_options = field_options(options, Target::Field(i));
+            let readonly = field_options.is_some_and(|field_options| field_options.readonly);
+
+            let response = ui.label(field_label(field_options, field_info.name()));
+            let field = value.field_at_mut(i).unwrap();
+            if readonly {
+                self.field_context_menu_readonly(&response, field);
+            } else {
+                let parent = FieldParent {
+                    type_id: type_info.type_id(),
+                    variant: None,
+                    field: Access::Field(field_info.name().into()),
+                };
+                changed |= self.field_context_menu(
+                    &response,
                     field,
-                    ui,
-                    id.with(i),
                     inspector_options_struct_field(options, i),
+                    Some(parent),
                 );
-                ui.end_row();
             }
+            #[cfg(feature = "documentation")]
+            show_docs(response, field_info.docs());
+
+            changed |= self.ui_for_field(
+                field,
+                ui,
+                id.with(i),
+                inspector_options_struct_field(options, i),
+                field_options,
+            );
         });
         changed
     }
@@ -523,23 +968,26 @@ impl InspectorUi<'_, '_> {
             return;
         };
 
-        Grid::new(id).show(ui, |ui| {
-            for i in 0..value.field_len() {
-                let field_info = type_info.field_at(i).unwrap();
-
-                let _response = ui.label(field_info.name());
-                #[cfg(feature = "documentation")]
-                show_docs(_response, field_info.docs());
-
-                let field = value.field_at(i).unwrap();
-                self.ui_for_reflect_readonly_with_options(
-                    field,
-                    ui,
-                    id.with(i),
-                    inspector_options_struct_field(options, i),
-                );
-                ui.end_row();
-            }
+        let groups = struct_field_groups(options, value.field_len(), |i| {
+            field_visible(field_options(options, Target::Field(i)), value)
+        });
+        let label = |i| struct_field_label(options, type_info, i);
+        show_field_groups(ui, id, groups, label, |ui, i| {
+            let field_info = type_info.field_at(i).unwrap();
+            let field_options = field_options(options, Target::Field(i));
+
+            let response = ui.label(field_label(field_options, field_info.name()));
+            let field = value.field_at(i).unwrap();
+            self.field_context_menu_readonly(&response, field);
+            #[cfg(feature = "documentation")]
+            show_docs(response, field_info.docs());
+
+            self.ui_for_reflect_readonly_with_options(
+                field,
+                ui,
+                id.with(i),
+                inspector_options_struct_field(options, i),
+            );
         });
     }
 
@@ -553,26 +1001,38 @@ impl InspectorUi<'_, '_> {
         projector: impl ProjectorReflect,
     ) -> bool {
         let mut changed = false;
-        Grid::new(id).show(ui, |ui| {
-            for (i, field) in info.iter().enumerate() {
-                let _response = ui.label(field.name());
-                #[cfg(feature = "documentation")]
-                show_docs(_response, field.docs());
-
-                changed |= self.ui_for_reflect_many_with_options(
-                    field.type_id(),
-                    field.type_path(),
-                    ui,
-                    id.with(i),
-                    inspector_options_struct_field(options, i),
-                    values,
-                    &|a| match projector(a).reflect_mut() {
-                        ReflectMut::Struct(strukt) => strukt.field_at_mut(i).unwrap(),
-                        _ => unreachable!(),
-                    },
-                );
-                ui.end_row();
-            }
+        let visible: Vec<bool> = (0..info.field_len())
+            .map(|i| {
+                field_visible_many(field_options(options, Target::Field(i)), values, &projector)
+            })
+            .collect();
+        let groups = struct_field_groups(options, info.field_len(), |i| visible[i]);
+        let label = |i| struct_field_label(options, info, i);
+        show_field_groups(ui, id, groups, label, |ui, i| {
+            let field = info.field_at(i).unwrap();
+            let field_options = field_options(options, Target::Field(i));
+            let readonly = field_options.is_some_and(|field_options| field_options.readonly);
+
+            let _response = ui.label(field_label(field_options, field.name()));
+            #[cfg(feature = "documentation")]
+            show_docs(_response, field.docs());
+
+            changed |= ui
+                .add_enabled_ui(!readonly, |ui| {
+                    self.ui_for_reflect_many_with_options(
+                        field.type_id(),
+                        field.type_path(),
+                        ui,
+                        id.with(i),
+                        inspector_options_struct_field(options, i),
+                        values,
+                        &|a| match projector(a).reflect_mut() {
+                            ReflectMut::Struct(strukt) => strukt.field_at_mut(i).unwrap(),
+                            _ => unreachable!(),
+                        },
+                    )
+                })
+                .inner;
         });
         changed
     }
@@ -584,18 +1044,43 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) -> bool {
+        let parent_type_id = value.get_represented_type_info().map(|info| info.type_id());
         maybe_grid(value.field_len(), ui, id, |ui, label| {
             (0..value.field_len())
                 .map(|i| {
-                    if label {
-                        ui.label(i.to_string());
+                    let field_options = field_options(options, Target::Field(i));
+                    if !field_visible(field_options, &*value) {
+                        return false;
                     }
+                    let readonly =
+                        field_options.is_some_and(|field_options| field_options.readonly);
+
                     let field = value.field_mut(i).unwrap();
-                    let changed = self.ui_for_reflect_with_options(
+                    let mut changed = false;
+                    if label {
+                        let response = ui.label(field_label(field_options, &i.to_string()));
+                        if readonly {
+                            self.field_context_menu_readonly(&response, field);
+                        } else {
+                            let parent = parent_type_id.map(|type_id| FieldParent {
+                                type_id,
+                                variant: None,
+                                field: Access::TupleIndex(i),
+                            });
+                            changed |= self.field_context_menu(
+                                &response,
+                                field,
+                                inspector_options_struct_field(options, i),
+                                parent,
+                            );
+                        }
+                    }
+                    changed |= self.ui_for_field(
                         field,
                         ui,
                         id.with(i),
                         inspector_options_struct_field(options, i),
+                        field_options,
                     );
                     ui.end_row();
                     changed
@@ -613,10 +1098,16 @@ impl InspectorUi<'_, '_> {
     ) {
         maybe_grid_readonly(value.field_len(), ui, id, |ui, label| {
             for i in 0..value.field_len() {
-                if label {
-                    ui.label(i.to_string());
+                let field_options = field_options(options, Target::Field(i));
+                if !field_visible(field_options, value) {
+                    continue;
                 }
+
                 let field = value.field(i).unwrap();
+                if label {
+                    let response = ui.label(field_label(field_options, &i.to_string()));
+                    self.field_context_menu_readonly(&response, field);
+                }
                 self.ui_for_reflect_readonly_with_options(
                     field,
                     ui,
@@ -641,21 +1132,32 @@ impl InspectorUi<'_, '_> {
             info.iter()
                 .enumerate()
                 .map(|(i, field)| {
+                    let field_options = field_options(options, Target::Field(i));
+                    if !field_visible_many(field_options, values, &projector) {
+                        return false;
+                    }
+                    let readonly =
+                        field_options.is_some_and(|field_options| field_options.readonly);
+
                     if label {
-                        ui.label(i.to_string());
+                        ui.label(field_label(field_options, &i.to_string()));
                     }
-                    let changed = self.ui_for_reflect_many_with_options(
-                        field.type_id(),
-                        field.type_path(),
-                        ui,
-                        id.with(i),
-                        inspector_options_struct_field(options, i),
-                        values,
-                        &|a| match projector(a).reflect_mut() {
-                            ReflectMut::TupleStruct(strukt) => strukt.field_mut(i).unwrap(),
-                            _ => unreachable!(),
-                        },
-                    );
+                    let changed = ui
+                        .add_enabled_ui(!readonly, |ui| {
+                            self.ui_for_reflect_many_with_options(
+                                field.type_id(),
+                                field.type_path(),
+                                ui,
+                                id.with(i),
+                                inspector_options_struct_field(options, i),
+                                values,
+                                &|a| match projector(a).reflect_mut() {
+                                    ReflectMut::TupleStruct(strukt) => strukt.field_mut(i).unwrap(),
+                                    _ => unreachable!(),
+                                },
+                            )
+                        })
+                        .inner;
                     ui.end_row();
                     changed
                 })
@@ -670,14 +1172,27 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) -> bool {
+        let parent_type_id = value.get_represented_type_info().map(|info| info.type_id());
         maybe_grid(value.field_len(), ui, id, |ui, label| {
             (0..value.field_len())
                 .map(|i| {
+                    let field = value.field_mut(i).unwrap();
+                    let mut changed = false;
                     if label {
-                        ui.label(i.to_string());
+                        let response = ui.label(i.to_string());
+                        let parent = parent_type_id.map(|type_id| FieldParent {
+                            type_id,
+                            variant: None,
+                            field: Access::TupleIndex(i),
+                        });
+                        changed |= self.field_context_menu(
+                            &response,
+                            field,
+                            inspector_options_struct_field(options, i),
+                            parent,
+                        );
                     }
-                    let field = value.field_mut(i).unwrap();
-                    let changed = self.ui_for_reflect_with_options(
+                    changed |= self.ui_for_reflect_with_options(
                         field,
                         ui,
                         id.with(i),
@@ -699,10 +1214,11 @@ impl InspectorUi<'_, '_> {
     ) {
         maybe_grid_readonly(value.field_len(), ui, id, |ui, label| {
             for i in 0..value.field_len() {
+                let field = value.field(i).unwrap();
                 if label {
-                    ui.label(i.to_string());
+                    let response = ui.label(i.to_string());
+                    self.field_context_menu_readonly(&response, field);
                 }
-                let field = value.field(i).unwrap();
                 self.ui_for_reflect_readonly_with_options(
                     field,
                     ui,
@@ -765,6 +1281,7 @@ impl InspectorUi<'_, '_> {
             let Some(TypeInfo::List(info)) = list.get_represented_type_info() else {
                 continue;
             };
+            let snapshot = ValueSnapshot::before_edit(ui, list.as_partial_reflect());
             match op {
                 AddElement(i) => {
                     let default = self
@@ -805,6 +1322,7 @@ impl InspectorUi<'_, '_> {
                     changed = true;
                 }
             }
+            snapshot.record(changed);
         }
         changed
     }
@@ -818,32 +1336,38 @@ impl InspectorUi<'_, '_> {
     ) -> bool {
         use ListOp::*;
         let mut changed = false;
+        let (list_options, options) = split_list_options(options);
 
         ui.vertical(|ui| {
             let mut op = None;
             let len = list.len();
-            if len == 0 && ui_for_empty_list(ui) {
+            if len == 0 && ui_for_empty_list(ui, list_options) {
                 op = Some(AddElement(0))
             }
-            for i in 0..len {
-                egui::Grid::new((id, i)).show(ui, |ui| {
-                    ui.label(i.to_string());
-                    let val = list.get_mut(i).unwrap();
-                    ui.horizontal_top(|ui| {
-                        changed |= self.ui_for_reflect_with_options(val, ui, id.with(i), options);
+            ui_for_long_collection(ui, id, len, |ui, rows| {
+                for i in rows {
+                    egui::Grid::new((id, i)).show(ui, |ui| {
+                        let label = list_element_label(list_options, i, list.get(i).unwrap());
+                        let response = ui.label(label);
+                        let val = list.get_mut(i).unwrap();
+                        changed |= self.field_context_menu(&response, val, options, None);
+                        ui.horizontal_top(|ui| {
+                            changed |=
+                                self.ui_for_reflect_with_options(val, ui, id.with(i), options);
+                        });
+                        ui.end_row();
+
+                        let item_op = ui_for_list_controls(ui, i, len, list_options);
+                        if item_op.is_some() {
+                            op = item_op;
+                        }
                     });
-                    ui.end_row();
 
-                    let item_op = ui_for_list_controls(ui, i, len);
-                    if item_op.is_some() {
-                        op = item_op;
+                    if i != len - 1 {
+                        ui.separator();
                     }
-                });
-
-                if i != len - 1 {
-                    ui.separator();
                 }
-            }
+            });
 
             let Some(TypeInfo::List(info)) = list.get_represented_type_info() else {
                 return;
@@ -875,18 +1399,23 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
+        let (list_options, options) = split_list_options(options);
         ui.vertical(|ui| {
             let len = list.len();
-            for i in 0..len {
-                let val = list.get(i).unwrap();
-                ui.horizontal_top(|ui| {
-                    self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options)
-                });
+            ui_for_long_collection(ui, id, len, |ui, rows| {
+                for i in rows {
+                    let val = list.get(i).unwrap();
+                    ui.horizontal_top(|ui| {
+                        let response = ui.label(list_element_label(list_options, i, val));
+                        self.field_context_menu_readonly(&response, val);
+                        self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options)
+                    });
 
-                if i != len - 1 {
-                    ui.separator();
+                    if i != len - 1 {
+                        ui.separator();
+                    }
                 }
-            }
+            });
         });
     }
 
@@ -916,47 +1445,50 @@ impl InspectorUi<'_, '_> {
             ui.label("lists have different sizes, cannot multiedit");
             return changed;
         };
+        let (list_options, options) = split_list_options(options);
 
         ui.vertical(|ui| {
             let mut op = None;
 
-            if len == 0 && ui_for_empty_list(ui) {
+            if len == 0 && ui_for_empty_list(ui, list_options) {
                 op = Some(AddElement(0));
             }
 
-            for i in 0..len {
-                let mut items_at_i: Vec<&mut dyn PartialReflect> = values
-                    .iter_mut()
-                    .map(|value| match projector(*value).reflect_mut() {
-                        ReflectMut::List(list) => list.get_mut(i).unwrap(),
-                        _ => unreachable!(),
-                    })
-                    .collect();
-
-                egui::Grid::new((id, i)).show(ui, |ui| {
-                    ui.label(i.to_string());
-                    ui.horizontal_top(|ui| {
-                        changed |= self.ui_for_reflect_many_with_options(
-                            info.item_ty().id(),
-                            info.type_path(),
-                            ui,
-                            id.with(i),
-                            options,
-                            items_at_i.as_mut_slice(),
-                            &|a| a,
-                        );
-                    });
-                    ui.end_row();
-                    let item_op = ui_for_list_controls(ui, i, len);
-                    if item_op.is_some() {
-                        op = item_op;
-                    }
-                });
+            ui_for_long_collection(ui, id, len, |ui, rows| {
+                for i in rows {
+                    let mut items_at_i: Vec<&mut dyn PartialReflect> = values
+                        .iter_mut()
+                        .map(|value| match projector(*value).reflect_mut() {
+                            ReflectMut::List(list) => list.get_mut(i).unwrap(),
+                            _ => unreachable!(),
+                        })
+                        .collect();
 
-                if i != len - 1 {
-                    ui.separator();
+                    egui::Grid::new((id, i)).show(ui, |ui| {
+                        ui.label(list_element_label(list_options, i, &*items_at_i[0]));
+                        ui.horizontal_top(|ui| {
+                            changed |= self.ui_for_reflect_many_with_options(
+                                info.item_ty().id(),
+                                info.type_path(),
+                                ui,
+                                id.with(i),
+                                options,
+                                items_at_i.as_mut_slice(),
+                                &|a| a,
+                            );
+                        });
+                        ui.end_row();
+                        let item_op = ui_for_list_controls(ui, i, len, list_options);
+                        if item_op.is_some() {
+                            op = item_op;
+                        }
+                    });
+
+                    if i != len - 1 {
+                        ui.separator();
+                    }
                 }
-            }
+            });
 
             let error_id = id.with("error");
             let error = ui.data_mut(|data| *data.get_temp_mut_or_default::<bool>(error_id));
@@ -994,18 +1526,27 @@ impl InspectorUi<'_, '_> {
         }
         let mut to_delete: Option<usize> = None;
 
-        egui::Grid::new(id).show(ui, |ui| {
-            for i in 0..map.len() {
-                if let Some((key, value)) = map.get_at_mut(i) {
-                    self.ui_for_reflect_readonly_with_options(key, ui, id.with(i), &());
-                    changed |= self.ui_for_reflect_with_options(value, ui, id.with(i), &());
-                    if remove_button(ui).on_hover_text("Remove element").clicked() {
-                        to_delete = Some(i);
+        ui_for_long_collection(ui, id, map.len(), |ui, rows| {
+            egui::Grid::new(id).show(ui, |ui| {
+                for i in rows {
+                    if let Some((key, value)) = map.get_at_mut(i) {
+                        // the key acts as the label of the value
+                        let response = ui
+                            .scope(|ui| {
+                                self.ui_for_reflect_readonly_with_options(key, ui, id.with(i), &())
+                            })
+                            .response;
+                        changed |= self.field_context_menu(&response, value, &(), None);
+                        changed |= self.ui_for_reflect_with_options(value, ui, id.with(i), &());
+                        if remove_button(ui).on_hover_text("Remove element").clicked() {
+                            to_delete = Some(i);
+                        }
+                        ui.end_row();
                     }
-                    ui.end_row();
                 }
-            }
-
+            });
+        });
+        egui::Grid::new(id.with("add")).show(ui, |ui| {
             self.map_add_element_ui(map, ui, id, &mut changed);
         });
 
@@ -1014,7 +1555,10 @@ impl InspectorUi<'_, '_> {
             // and mutably borrow the map to delete the element.
             let cloned_key = map.get_at(index).map(|(key, _)| key.to_dynamic());
             if let Some(key) = cloned_key {
+                let snapshot = ValueSnapshot::before_edit(ui, map.as_partial_reflect());
                 map.remove(key.as_ref());
+                snapshot.record(true);
+                changed = true;
             }
         }
 
@@ -1076,7 +1620,9 @@ impl InspectorUi<'_, '_> {
                         .data_mut(|data| data.get_temp::<Option<MapDraftElement>>(map_draft_id))
                         .flatten();
                     if let Some(draft) = draft {
+                        let snapshot = ValueSnapshot::before_edit(ui, map.as_partial_reflect());
                         map.insert_boxed(draft.key, draft.value);
+                        snapshot.record(true);
                         ui.data_mut(|data| data.remove_by_type::<Option<MapDraftElement>>());
                     }
                     *changed = true;
@@ -1093,6 +1639,74 @@ impl InspectorUi<'_, '_> {
         Some(())
     }
 
+    fn ui_for_reflect_map_many(
+        &mut self,
+        info: &MapInfo,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        values: &mut [&mut dyn PartialReflect],
+        projector: impl ProjectorReflect,
+    ) -> bool {
+        let mut changed = false;
+
+        let maps = values
+            .iter_mut()
+            .map(|value| match projector(*value).reflect_mut() {
+                ReflectMut::Map(map) => &*map,
+                _ => unreachable!(),
+            });
+        let (keys, same_keys) = common_map_keys(maps);
+
+        if keys.is_empty() {
+            ui.label("(Empty Map)");
+        }
+        let mut to_delete: Option<usize> = None;
+
+        ui_for_long_collection(ui, id, keys.len(), |ui, rows| {
+            egui::Grid::new(id).show(ui, |ui| {
+                for (i, key) in keys.iter().enumerate().skip(rows.start).take(rows.len()) {
+                    let mut values_at_key: Vec<&mut dyn PartialReflect> = values
+                        .iter_mut()
+                        .map(|value| match projector(*value).reflect_mut() {
+                            ReflectMut::Map(map) => map.get_mut(key.as_ref()).unwrap(),
+                            _ => unreachable!(),
+                        })
+                        .collect();
+
+                    self.ui_for_reflect_readonly_with_options(key.as_ref(), ui, id.with(i), &());
+                    changed |= self.ui_for_reflect_many_with_options(
+                        info.value_ty().id(),
+                        info.value_ty().path(),
+                        ui,
+                        id.with(i),
+                        &(),
+                        values_at_key.as_mut_slice(),
+                        &|a| a,
+                    );
+                    if remove_button(ui).on_hover_text("Remove element").clicked() {
+                        to_delete = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+
+        if !same_keys {
+            ui.label("Keys which are not present in every map are hidden");
+        }
+
+        if let Some(index) = to_delete {
+            for value in values.iter_mut() {
+                if let ReflectMut::Map(map) = projector(*value).reflect_mut() {
+                    map.remove(keys[index].as_ref());
+                }
+            }
+            changed = true;
+        }
+
+        changed
+    }
+
     fn ui_for_reflect_map_readonly(
         &mut self,
         map: &dyn Map,
@@ -1100,12 +1714,20 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         _options: &dyn Any,
     ) {
-        egui::Grid::new(id).show(ui, |ui| {
-            for (i, (key, value)) in map.iter().enumerate() {
-                self.ui_for_reflect_readonly_with_options(key, ui, id.with(i), &());
-                self.ui_for_reflect_readonly_with_options(value, ui, id.with(i), &());
-                ui.end_row();
-            }
+        ui_for_long_collection(ui, id, map.len(), |ui, rows| {
+            egui::Grid::new(id).show(ui, |ui| {
+                let entries = map.iter().enumerate().skip(rows.start).take(rows.len());
+                for (i, (key, value)) in entries {
+                    let response = ui
+                        .scope(|ui| {
+                            self.ui_for_reflect_readonly_with_options(key, ui, id.with(i), &())
+                        })
+                        .response;
+                    self.field_context_menu_readonly(&response, value);
+                    self.ui_for_reflect_readonly_with_options(value, ui, id.with(i), &());
+                    ui.end_row();
+                }
+            });
         });
     }
 
@@ -1153,24 +1775,36 @@ impl InspectorUi<'_, '_> {
                 ui_for_empty_set(ui);
             }
 
-            for (i, val) in set.iter().enumerate() {
-                egui::Grid::new((id, i)).show(ui, |ui| {
-                    ui.horizontal_top(|ui| {
-                        self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options);
-                    });
-                    ui.horizontal_top(|ui| {
-                        if remove_button(ui).on_hover_text("Remove element").clicked() {
-                            let copy = val.to_dynamic();
-                            op = Some(RemoveElement(copy));
-                        }
+            ui_for_long_collection(ui, id, len, |ui, rows| {
+                let elements = set.iter().enumerate().skip(rows.start).take(rows.len());
+                for (i, val) in elements {
+                    egui::Grid::new((id, i)).show(ui, |ui| {
+                        // set elements can't be edited in place, so they can only be copied
+                        let response = ui
+                            .horizontal_top(|ui| {
+                                self.ui_for_reflect_readonly_with_options(
+                                    val,
+                                    ui,
+                                    id.with(i),
+                                    options,
+                                );
+                            })
+                            .response;
+                        self.field_context_menu_readonly(&response, val);
+                        ui.horizontal_top(|ui| {
+                            if remove_button(ui).on_hover_text("Remove element").clicked() {
+                                let copy = val.to_dynamic();
+                                op = Some(RemoveElement(copy));
+                            }
+                        });
+                        ui.end_row();
                     });
-                    ui.end_row();
-                });
 
-                if i != len - 1 {
-                    ui.separator();
+                    if i != len - 1 {
+                        ui.separator();
+                    }
                 }
-            }
+            });
             let Some(TypeInfo::Set(set_info)) = set.get_represented_type_info() else {
                 return;
             };
@@ -1186,7 +1820,9 @@ impl InspectorUi<'_, '_> {
 
             // Respond to control interaction
             if let Some(op) = op {
+                let snapshot = ValueSnapshot::before_edit(ui, set.as_partial_reflect());
                 changed |= self.respond_to_set_op(set, &op);
+                snapshot.record(true);
             }
 
             let error = ui.data_mut(|data| *data.get_temp_mut_or_default::<bool>(error_id));
@@ -1279,15 +1915,21 @@ impl InspectorUi<'_, '_> {
     ) {
         let len = set.len();
         ui.vertical(|ui| {
-            for (i, val) in set.iter().enumerate() {
-                ui.horizontal_top(|ui| {
-                    self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options)
-                });
+            ui_for_long_collection(ui, id, len, |ui, rows| {
+                let elements = set.iter().enumerate().skip(rows.start).take(rows.len());
+                for (i, val) in elements {
+                    let response = ui
+                        .horizontal_top(|ui| {
+                            self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options)
+                        })
+                        .response;
+                    self.field_context_menu_readonly(&response, val);
 
-                if i != len - 1 {
-                    ui.separator();
+                    if i != len - 1 {
+                        ui.separator();
+                    }
                 }
-            }
+            });
         });
     }
 
@@ -1414,16 +2056,65 @@ impl InspectorUi<'_, '_> {
 
         ui.vertical(|ui| {
             let len = array.len();
-            for i in 0..len {
-                let val = array.get_mut(i).unwrap();
-                ui.horizontal_top(|ui| {
-                    changed |= self.ui_for_reflect_with_options(val, ui, id.with(i), options);
-                });
+            ui_for_long_collection(ui, id, len, |ui, rows| {
+                for i in rows {
+                    let val = array.get_mut(i).unwrap();
+                    ui.horizontal_top(|ui| {
+                        let response = ui.label(i.to_string());
+                        changed |= self.field_context_menu(&response, val, options, None);
+                        changed |= self.ui_for_reflect_with_options(val, ui, id.with(i), options);
+                    });
 
-                if i != len - 1 {
-                    ui.separator();
+                    if i != len - 1 {
+                        ui.separator();
+                    }
                 }
-            }
+            });
+        });
+
+        changed
+    }
+
+    fn ui_for_array_many(
+        &mut self,
+        info: &ArrayInfo,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        values: &mut [&mut dyn PartialReflect],
+        projector: impl ProjectorReflect,
+    ) -> bool {
+        let mut changed = false;
+
+        ui.vertical(|ui| {
+            let len = info.capacity();
+            ui_for_long_collection(ui, id, len, |ui, rows| {
+                for i in rows {
+                    let mut items_at_i: Vec<&mut dyn PartialReflect> = values
+                        .iter_mut()
+                        .map(|value| match projector(*value).reflect_mut() {
+                            ReflectMut::Array(array) => array.get_mut(i).unwrap(),
+                            _ => unreachable!(),
+                        })
+                        .collect();
+
+                    ui.horizontal_top(|ui| {
+                        changed |= self.ui_for_reflect_many_with_options(
+                            info.item_ty().id(),
+                            info.item_ty().path(),
+                            ui,
+                            id.with(i),
+                            options,
+                            items_at_i.as_mut_slice(),
+                            &|a| a,
+                        );
+                    });
+
+                    if i != len - 1 {
+                        ui.separator();
+                    }
+                }
+            });
         });
 
         changed
@@ -1438,16 +2129,20 @@ impl InspectorUi<'_, '_> {
     ) {
         ui.vertical(|ui| {
             let len = array.len();
-            for i in 0..len {
-                let val = array.get(i).unwrap();
-                ui.horizontal_top(|ui| {
-                    self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options);
-                });
+            ui_for_long_collection(ui, id, len, |ui, rows| {
+                for i in rows {
+                    let val = array.get(i).unwrap();
+                    ui.horizontal_top(|ui| {
+                        let response = ui.label(i.to_string());
+                        self.field_context_menu_readonly(&response, val);
+                        self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options);
+                    });
 
-                if i != len - 1 {
-                    ui.separator();
+                    if i != len - 1 {
+                        ui.separator();
+                    }
                 }
-            }
+            });
         });
     }
 
@@ -1466,15 +2161,24 @@ impl InspectorUi<'_, '_> {
             TypeInfo::Enum(info) => info,
             _ => unreachable!("invalid reflect impl: type info mismatch"),
         };
+        let (enum_options, options) =
+            enum_options(self.type_registry, type_info.type_id(), options);
 
         let mut changed = false;
 
         ui.vertical(|ui| {
-            let changed_variant =
-                self.ui_for_enum_variant_select(id, ui, value.variant_index(), type_info);
+            let changed_variant = self.ui_for_enum_variant_select(
+                id,
+                ui,
+                value.variant_index(),
+                type_info,
+                &enum_options,
+            );
             if let Some((_new_variant, dynamic_enum)) = changed_variant {
                 changed = true;
+                let snapshot = ValueSnapshot::before_edit(ui, value.as_partial_reflect());
                 value.apply(&dynamic_enum);
+                snapshot.record(true);
             }
             let variant_index = value.variant_index();
 
@@ -1483,7 +2187,54 @@ impl InspectorUi<'_, '_> {
                 maybe_grid_label_if(value.field_len(), ui, id, always_show_label, |ui, label| {
                     (0..value.field_len())
                         .map(|i| {
-                            if label {
+                            let field_options = field_options(
+                                options,
+                                Target::VariantField {
+                                    variant_index,
+                                    field_index: i,
+                                },
+                            );
+                            if !field_visible(field_options, &*value) {
+                                return false;
+                            }
+                            let readonly =
+                                field_options.is_some_and(|field_options| field_options.readonly);
+
+                            let response = label.then(|| {
+                                if let Some(name) = value.name_at(i) {
+                                    ui.label(field_label(field_options, name))
+                                } else {
+                                    ui.label(field_label(field_options, &i.to_string()))
+                                }
+                            });
+                            let parent = FieldParent {
+                                type_id: type_info.type_id(),
+                                variant: Some(value.variant_name().to_owned()),
+                                field: match value.name_at(i) {
+                                    Some(name) => Access::Field(name.to_owned().into()),
+                                    None => Access::TupleIndex(i),
+                                },
+                            };
+                            let field_value = value
+                                .field_at_mut(i)
+                                .expect("invalid reflect impl: field len");
+                            let mut changed = false;
+                            if let Some(response) = response {
+                                if readonly {
+                                    self.field_context_menu_readonly(&response, field_value);
+                                } else {
+                                    changed |= self.field_context_menu(
+                                        &response,
+                                        field_value,
+                                        inspector_options_enum_variant_field(
+                                            options,
+                                            variant_index,
+                                            i,
+                                        ),
+                                        Some(parent),
+                                    );
+                                }
+
                                 #[cfg(feature = "documentation")]
                                 let field_docs = type_info.variant_at(variant_index).and_then(
                                     |info| match info {
@@ -1491,23 +2242,15 @@ impl InspectorUi<'_, '_> {
                                         _ => None,
                                     },
                                 );
-
-                                let _response = if let Some(name) = value.name_at(i) {
-                                    ui.label(name)
-                                } else {
-                                    ui.label(i.to_string())
-                                };
                                 #[cfg(feature = "documentation")]
-                                show_docs(_response, field_docs);
+                                show_docs(response, field_docs);
                             }
-                            let field_value = value
-                                .field_at_mut(i)
-                                .expect("invalid reflect impl: field len");
-                            let changed = self.ui_for_reflect_with_options(
+                            changed |= self.ui_for_field(
                                 field_value,
                                 ui,
                                 id.with(i),
                                 inspector_options_enum_variant_field(options, variant_index, i),
+                                field_options,
                             );
                             ui.end_row();
                             changed
@@ -1540,11 +2283,14 @@ impl InspectorUi<'_, '_> {
                     }),
             );
 
+        let (enum_options, options) = enum_options(self.type_registry, info.type_id(), options);
+
         if let Some(variant_index) = same_variant {
             let mut variant = info.variant_at(variant_index).unwrap();
 
             ui.vertical(|ui| {
-                let variant_changed = self.ui_for_enum_variant_select(id, ui, variant_index, info);
+                let variant_changed =
+                    self.ui_for_enum_variant_select(id, ui, variant_index, info, &enum_options);
                 if let Some((new_variant_idx, dynamic_enum)) = variant_changed {
                     changed = true;
                     variant = info.variant_at(new_variant_idx).unwrap();
@@ -1564,39 +2310,60 @@ impl InspectorUi<'_, '_> {
                 let always_show_label = matches!(variant, VariantInfo::Struct(_));
                 changed |=
                     maybe_grid_label_if(field_len, ui, id, always_show_label, |ui, label| {
-                        let handle = |(field_index, field_name, field_type_id, field_type_name)| {
-                            if label {
-                                ui.label(field_name);
-                            }
+                        let handle =
+                            |(field_index, field_name, field_type_id, field_type_name): (
+                                usize,
+                                Cow<str>,
+                                TypeId,
+                                &str,
+                            )| {
+                                let field_options = field_options(
+                                    options,
+                                    Target::VariantField {
+                                        variant_index,
+                                        field_index,
+                                    },
+                                );
+                                if !field_visible_many(field_options, values, projector) {
+                                    return false;
+                                }
+                                let readonly = field_options
+                                    .is_some_and(|field_options| field_options.readonly);
 
-                            let mut variants_across: Vec<&mut dyn PartialReflect> = values
-                                .iter_mut()
-                                .map(|value| match projector(*value).reflect_mut() {
-                                    ReflectMut::Enum(value) => {
-                                        value.field_at_mut(field_index).unwrap()
-                                    }
-                                    _ => unreachable!(),
-                                })
-                                .collect();
+                                if label {
+                                    ui.label(field_label(field_options, &field_name));
+                                }
 
-                            self.ui_for_reflect_many_with_options(
-                                field_type_id,
-                                field_type_name,
-                                ui,
-                                id.with(field_index),
-                                inspector_options_enum_variant_field(
-                                    options,
-                                    variant_index,
-                                    field_index,
-                                ),
-                                variants_across.as_mut_slice(),
-                                &|a| a,
-                            );
+                                let mut variants_across: Vec<&mut dyn PartialReflect> = values
+                                    .iter_mut()
+                                    .map(|value| match projector(*value).reflect_mut() {
+                                        ReflectMut::Enum(value) => {
+                                            value.field_at_mut(field_index).unwrap()
+                                        }
+                                        _ => unreachable!(),
+                                    })
+                                    .collect();
+
+                                ui.add_enabled_ui(!readonly, |ui| {
+                                    self.ui_for_reflect_many_with_options(
+                                        field_type_id,
+                                        field_type_name,
+                                        ui,
+                                        id.with(field_index),
+                                        inspector_options_enum_variant_field(
+                                            options,
+                                            variant_index,
+                                            field_index,
+                                        ),
+                                        variants_across.as_mut_slice(),
+                                        &|a| a,
+                                    )
+                                });
 
-                            ui.end_row();
+                                ui.end_row();
 
-                            false
-                        };
+                                false
+                            };
 
                         match variant {
                             VariantInfo::Struct(info) => info
@@ -1642,58 +2409,67 @@ impl InspectorUi<'_, '_> {
         ui: &mut egui::Ui,
         active_variant_idx: usize,
         info: &bevy_reflect::EnumInfo,
+        enum_options: &EnumOptions,
     ) -> Option<(usize, DynamicEnum)> {
         let mut changed_variant = None;
+        let display = enum_variant_display(info, enum_options);
+
+        let mut variants_ui = |ui: &mut egui::Ui| {
+            for (i, variant) in info.iter().enumerate() {
+                let variant_name = variant.name();
+                let is_active_variant = i == active_variant_idx;
+                if !is_active_variant && enum_options.is_hidden(variant_name) {
+                    continue;
+                }
 
-        ui.horizontal_top(|ui| {
-            egui::ComboBox::new(id.with("select"), "")
-                .selected_text(info.variant_names()[active_variant_idx])
-                .show_ui(ui, |ui| {
-                    for (i, variant) in info.iter().enumerate() {
-                        let variant_name = variant.name();
-                        let is_active_variant = i == active_variant_idx;
-
-                        let variant_is_constructable =
-                            variant_constructable(self.type_registry, variant);
-
-                        ui.add_enabled_ui(variant_is_constructable.is_ok(), |ui| {
-                            let mut variant_label_response =
-                                ui.selectable_label(is_active_variant, variant_name);
-
-                            if let Err(fields) = variant_is_constructable {
-                                variant_label_response = variant_label_response
-                                    .on_disabled_hover_ui(|ui| {
-                                        errors::unconstructable_variant(
-                                            ui,
-                                            info.type_path(),
-                                            variant_name,
-                                            &fields,
-                                        );
-                                    });
-                            }
+                let variant_is_constructable = variant_constructable(self.type_registry, variant);
 
-                            /*let res = variant_label_response.on_hover_ui(|ui| {
-                                if !unconstructable_variants.is_empty() {
-                                    errors::unconstructable_variants(
-                                        ui,
-                                        info.type_name(),
-                                        &unconstructable_variants,
-                                    );
-                                }
-                            });*/
+                ui.add_enabled_ui(variant_is_constructable.is_ok(), |ui| {
+                    let label = enum_options.label(variant_name);
+                    let mut variant_label_response = match display {
+                        EnumDisplay::Radio => ui.radio(is_active_variant, label),
+                        _ => ui.selectable_label(is_active_variant, label),
+                    };
 
-                            if variant_label_response.clicked()
-                                && let Ok(dynamic_enum) =
-                                    self.construct_default_variant(variant, ui)
-                            {
-                                changed_variant = Some((i, dynamic_enum));
-                            };
-                        });
+                    if let Err(fields) = variant_is_constructable {
+                        variant_label_response =
+                            variant_label_response.on_disabled_hover_ui(|ui| {
+                                errors::unconstructable_variant(
+                                    ui,
+                                    info.type_path(),
+                                    variant_name,
+                                    &fields,
+                                );
+                            });
                     }
 
-                    false
+                    if variant_label_response.clicked()
+                        && let Ok(dynamic_enum) = self.construct_default_variant(variant, ui)
+                    {
+                        changed_variant = Some((i, dynamic_enum));
+                    };
                 });
-        });
+            }
+        };
+
+        match display {
+            EnumDisplay::Radio => {
+                ui.horizontal_wrapped(variants_ui);
+            }
+            EnumDisplay::Segmented => {
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    variants_ui(ui);
+                });
+            }
+            _ => {
+                ui.horizontal_top(|ui| {
+                    egui::ComboBox::new(id.with("select"), "")
+                        .selected_text(enum_options.label(info.variant_names()[active_variant_idx]))
+                        .show_ui(ui, variants_ui);
+                });
+            }
+        }
 
         changed_variant
     }
@@ -1705,12 +2481,45 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
+        let info = match value.get_represented_type_info() {
+            Some(TypeInfo::Enum(info)) => Some(info),
+            _ => None,
+        };
+        let (enum_options, options) = match info {
+            Some(info) => enum_options(self.type_registry, info.type_id(), options),
+            None => (EnumOptions::default(), options),
+        };
+        let display = info.map_or(EnumDisplay::ComboBox, |info| {
+            enum_variant_display(info, &enum_options)
+        });
+
         ui.vertical(|ui| {
             let active_variant = value.variant_name();
-            ui.add_enabled_ui(false, |ui| {
-                egui::ComboBox::new(id, "")
-                    .selected_text(active_variant)
-                    .show_ui(ui, |_| {})
+            ui.add_enabled_ui(false, |ui| match (display, info) {
+                (EnumDisplay::Radio | EnumDisplay::Segmented, Some(info)) => {
+                    ui.horizontal_wrapped(|ui| {
+                        if display == EnumDisplay::Segmented {
+                            ui.spacing_mut().item_spacing.x = 0.0;
+                        }
+                        for variant_name in info.variant_names() {
+                            let is_active_variant = *variant_name == active_variant;
+                            if !is_active_variant && enum_options.is_hidden(variant_name) {
+                                continue;
+                            }
+                            let label = enum_options.label(variant_name);
+                            if display == EnumDisplay::Radio {
+                                ui.add(egui::RadioButton::new(is_active_variant, label));
+                            } else {
+                                ui.add(egui::SelectableLabel::new(is_active_variant, label));
+                            }
+                        }
+                    });
+                }
+                _ => {
+                    egui::ComboBox::new(id, "")
+                        .selected_text(enum_options.label(active_variant))
+                        .show_ui(ui, |_| {});
+                }
             });
 
             let always_show_label = matches!(value.variant_type(), VariantType::Struct);
@@ -1721,15 +2530,27 @@ impl InspectorUi<'_, '_> {
                 always_show_label,
                 |ui, label| {
                     for i in 0..value.field_len() {
-                        if label {
-                            if let Some(name) = value.name_at(i) {
-                                ui.label(name);
-                            } else {
-                                ui.label(i.to_string());
-                            }
+                        let field_options = field_options(
+                            options,
+                            Target::VariantField {
+                                variant_index: value.variant_index(),
+                                field_index: i,
+                            },
+                        );
+                        if !field_visible(field_options, value) {
+                            continue;
                         }
+
                         let field_value =
                             value.field_at(i).expect("invalid reflect impl: field len");
+                        if label {
+                            let response = if let Some(name) = value.name_at(i) {
+                                ui.label(field_label(field_options, name))
+                            } else {
+                                ui.label(field_label(field_options, &i.to_string()))
+                            };
+                            self.field_context_menu_readonly(&response, field_value);
+                        }
                         self.ui_for_reflect_readonly_with_options(
                             field_value,
                             ui,
@@ -1747,10 +2568,30 @@ impl InspectorUi<'_, '_> {
         &mut self,
         value: &mut dyn PartialReflect,
         ui: &mut egui::Ui,
-        _id: egui::Id,
-        _options: &dyn Any,
+        id: egui::Id,
+        options: &dyn Any,
     ) -> bool {
-        errors::reflect_value_no_impl(ui, value.reflect_short_type_path());
+        let type_id = value.get_represented_type_info().map(|info| info.type_id());
+        if let Some(registration) =
+            type_id.and_then(|type_id| ron_registration(self.type_registry, type_id))
+        {
+            let current = to_ron(value, self.type_registry);
+            let Some(new_value) = self.ron_editor(ui, id, current.map(Some), registration) else {
+                return false;
+            };
+            let snapshot = ValueSnapshot::before_edit(ui, value);
+            let changed = match value.try_apply(new_value.as_ref()) {
+                Ok(()) => true,
+                Err(error) => {
+                    errors::invalid_ron(ui, &error.to_string());
+                    false
+                }
+            };
+            snapshot.record(changed);
+            return changed;
+        }
+
+        self.ui_for_value_readonly(value, ui, id, options);
         false
     }
 
@@ -1761,6 +2602,25 @@ impl InspectorUi<'_, '_> {
         _id: egui::Id,
         _options: &dyn Any,
     ) {
+        let registration = value
+            .get_represented_type_info()
+            .and_then(|info| self.type_registry.get(info.type_id()));
+        if registration
+            .is_some_and(|registration| registration.data::<ReflectSerialize>().is_some())
+            && let Ok(ron) = to_ron(value, self.type_registry)
+        {
+            ui.add(egui::TextEdit::singleline(&mut ron.as_str()).code_editor());
+            return;
+        }
+
+        // opaque types with `#[reflect(Debug)]` format using their `Debug` impl,
+        // the others only print their type path
+        let debug = format!("{value:?}");
+        if debug != format!("Reflect({})", value.reflect_type_path()) {
+            ui.add(egui::TextEdit::singleline(&mut debug.as_str()).code_editor());
+            return;
+        }
+
         errors::reflect_value_no_impl(ui, value.reflect_short_type_path());
     }
 
@@ -1768,11 +2628,337 @@ impl InspectorUi<'_, '_> {
         &mut self,
         info: &OpaqueInfo,
         ui: &mut egui::Ui,
-        _id: egui::Id,
+        id: egui::Id,
         _options: &dyn Any,
+        values: &mut [&mut dyn PartialReflect],
+        projector: &dyn ProjectorReflect,
     ) -> bool {
-        errors::reflect_value_no_impl(ui, info.type_path());
-        false
+        let Some(registration) = ron_registration(self.type_registry, info.type_id()) else {
+            errors::reflect_value_no_impl(ui, info.type_path());
+            return false;
+        };
+
+        let current = values
+            .iter_mut()
+            .map(|value| to_ron(projector(*value), self.type_registry))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|values| iter_all_eq(values.into_iter()));
+        let Some(new_value) = self.ron_editor(ui, id, current, registration) else {
+            return false;
+        };
+
+        let mut changed = false;
+        for value in values.iter_mut() {
+            match projector(*value).try_apply(new_value.as_ref()) {
+                Ok(()) => changed = true,
+                Err(error) => {
+                    errors::invalid_ron(ui, &error.to_string());
+                    break;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Text box for editing a value as RON.
+    ///
+    /// `current` is the serialized value, or `None` if multiple values with different contents are edited at once.
+    /// Returns the parsed value when the text was changed to valid RON, and shows the parse error inline otherwise.
+    fn ron_editor(
+        &mut self,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        current: Result<Option<String>, String>,
+        registration: &TypeRegistration,
+    ) -> Option<Box<dyn PartialReflect>> {
+        let current = match current {
+            Ok(current) => current,
+            Err(error) => {
+                errors::invalid_ron(ui, &error);
+                return None;
+            }
+        };
+
+        let edit_id = id.with("ron");
+        let error_id = id.with("ron_error");
+        let has_focus = ui.memory(|memory| memory.has_focus(edit_id));
+
+        // keep the text the user is typing, even if it doesn't parse yet
+        let mut text = match has_focus {
+            true => ui.data(|data| data.get_temp::<String>(edit_id)),
+            false => None,
+        }
+        .or_else(|| current.clone())
+        .unwrap_or_default();
+
+        let mut edit = egui::TextEdit::singleline(&mut text)
+            .id(edit_id)
+            .code_editor();
+        if current.is_none() {
+            edit = edit.hint_text("(mixed)");
+        }
+        let response = ui.add(edit);
+
+        let mut new_value = None;
+        if response.changed() {
+            match from_ron(&text, registration, self.type_registry) {
+                Ok(value) => {
+                    new_value = Some(value);
+                    ui.data_mut(|data| data.remove::<String>(error_id));
+                }
+                Err(error) => ui.data_mut(|data| data.insert_temp(error_id, error)),
+            }
+        }
+
+        if response.has_focus() {
+            ui.data_mut(|data| data.insert_temp(edit_id, text));
+        } else {
+            ui.data_mut(|data| {
+                data.remove::<String>(edit_id);
+                data.remove::<String>(error_id);
+            });
+        }
+
+        if let Some(error) = ui.data(|data| data.get_temp::<String>(error_id)) {
+            errors::invalid_ron(ui, &error);
+        }
+
+        new_value
+    }
+}
+
+/// The value containing a field, whose [`ReflectDefault`] takes precedence over the field type's when resetting the field
+struct FieldParent {
+    type_id: TypeId,
+    /// For fields of enum variants, the variant the field belongs to
+    variant: Option<String>,
+    field: Access<'static>,
+}
+
+/// A value copied using the field context menu, stored in egui's memory
+#[derive(Clone)]
+struct CopiedValue {
+    type_id: TypeId,
+    ron: String,
+}
+
+impl CopiedValue {
+    fn id() -> egui::Id {
+        egui::Id::new("bevy_inspector_egui::copied_value")
+    }
+}
+
+impl InspectorUi<'_, '_> {
+    /// Right click menu on `response` to copy `value` as RON, paste a previously copied value of the same type
+    /// or RON from the clipboard using the paste shortcut, or reset it to its default value.
+    ///
+    /// This is the menu shown on the labels of fields and collection elements, which can be added to e.g. the header of a component with this method.
+    pub fn value_context_menu(
+        &mut self,
+        response: &egui::Response,
+        value: &mut dyn PartialReflect,
+    ) -> bool {
+        self.field_context_menu(response, value, &(), None)
+    }
+
+    /// Right click menu on `response` to copy `value` as RON, see [`InspectorUi::value_context_menu`].
+    pub fn value_context_menu_readonly(
+        &mut self,
+        response: &egui::Response,
+        value: &dyn PartialReflect,
+    ) {
+        self.field_context_menu_readonly(response, value);
+    }
+
+    /// Right click menu on the label of a field, to copy its value as RON, paste a previously copied value of the same type
+    /// or RON from the clipboard, or reset it to its default value.
+    ///
+    /// Lists are only pasted or reset if the new length is allowed by the [`ListOptions`] in `options`.
+    fn field_context_menu(
+        &mut self,
+        response: &egui::Response,
+        value: &mut dyn PartialReflect,
+        options: &dyn Any,
+        parent: Option<FieldParent>,
+    ) -> bool {
+        let mut changed = false;
+        let response = response.interact(egui::Sense::click());
+        response.context_menu(|ui| {
+            let default = self.default_field_value(value, parent.as_ref());
+            let default_len = default
+                .as_ref()
+                .map_or(Ok(()), |default| check_list_len(options, default.as_ref()));
+            let reset = ui.add_enabled(
+                default.is_some() && default_len.is_ok(),
+                egui::Button::new("Reset to default"),
+            );
+            let reset = match default_len {
+                Ok(()) => {
+                    reset.on_disabled_hover_text("No ReflectDefault is registered for this field")
+                }
+                Err(error) => reset.on_disabled_hover_text(error),
+            };
+            if let Some(default) = default.filter(|_| reset.clicked()) {
+                let snapshot = ValueSnapshot::before_edit(ui, value);
+                changed |= value.try_apply(default.as_ref()).is_ok();
+                snapshot.record(changed);
+                ui.close_menu();
+            }
+
+            self.copy_button(ui, value);
+
+            let Some(registration) = value
+                .get_represented_type_info()
+                .and_then(|info| self.type_registry.get(info.type_id()))
+            else {
+                return;
+            };
+            let copied = ui
+                .data(|data| data.get_temp::<CopiedValue>(CopiedValue::id()))
+                .filter(|copied| copied.type_id == registration.type_id());
+            // the paste shortcut pastes the text of the system clipboard, so values copied
+            // outside of the inspector or in an earlier session can be pasted as well
+            let clipboard = ui.input(|input| {
+                input.events.iter().find_map(|event| match event {
+                    egui::Event::Paste(text) => Some(text.clone()),
+                    _ => None,
+                })
+            });
+            let paste_shortcut = ui.ctx().format_shortcut(&egui::KeyboardShortcut::new(
+                egui::Modifiers::COMMAND,
+                egui::Key::V,
+            ));
+
+            let error_id = response.id.with("paste_error");
+            let paste = ui
+                .add_enabled(copied.is_some(), egui::Button::new("Paste"))
+                .on_hover_text(format!(
+                    "Paste the value copied in the inspector, or press {paste_shortcut} to paste RON from the clipboard"
+                ))
+                .on_disabled_hover_text(format!(
+                    "No value of this type has been copied in the inspector, press {paste_shortcut} to paste RON from the clipboard"
+                ));
+            let ron = match (clipboard, copied) {
+                (Some(text), _) => Some(text),
+                (None, Some(copied)) if paste.clicked() => Some(copied.ron),
+                (None, _) => None,
+            };
+            if let Some(ron) = ron {
+                let snapshot = ValueSnapshot::before_edit(ui, value);
+                let result =
+                    from_ron(&ron, registration, self.type_registry).and_then(|new_value| {
+                        check_list_len(options, new_value.as_ref())?;
+                        value
+                            .try_apply(new_value.as_ref())
+                            .map_err(|error| error.to_string())
+                    });
+                snapshot.record(result.is_ok());
+                match result {
+                    Ok(()) => {
+                        changed = true;
+                        ui.data_mut(|data| data.remove::<String>(error_id));
+                        ui.close_menu();
+                    }
+                    Err(error) => ui.data_mut(|data| data.insert_temp(error_id, error)),
+                }
+            }
+            if let Some(error) = ui.data(|data| data.get_temp::<String>(error_id)) {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+        });
+        changed
+    }
+
+    /// Draws a field, in a read only way or with validation if its [`FieldOptions`] say so.
+    fn ui_for_field(
+        &mut self,
+        field: &mut dyn PartialReflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        field_options: Option<&FieldOptions>,
+    ) -> bool {
+        if field_options.is_some_and(|field_options| field_options.readonly) {
+            self.ui_for_reflect_readonly_with_options(field, ui, id, options);
+            return false;
+        }
+
+        let changed = self.ui_for_reflect_with_options(field, ui, id, options);
+        if let Some(validate) = field_options.and_then(|field_options| field_options.validate)
+            && let Some(field) = field.try_as_reflect()
+            && let Err(error) = validate(field.as_any())
+        {
+            errors::invalid_value(ui, &error);
+        }
+        changed
+    }
+
+    /// Default value of a field, taken from the default of the parent if it has one, or else from the field's type.
+    fn default_field_value(
+        &mut self,
+        value: &dyn PartialReflect,
+        parent: Option<&FieldParent>,
+    ) -> Option<Box<dyn PartialReflect>> {
+        if let Some(parent) = parent
+            && let Some(parent_default) = self.get_default_value_for(parent.type_id)
+        {
+            let same_variant = match (&parent.variant, parent_default.reflect_ref()) {
+                (None, _) => true,
+                (Some(variant), ReflectRef::Enum(parent_default)) => {
+                    parent_default.variant_name() == variant
+                }
+                (Some(_), _) => false,
+            };
+            let path = ParsedPath::from(vec![parent.field.clone()]);
+            if same_variant
+                && let Ok(field) = (&path).reflect_element(parent_default.as_partial_reflect())
+            {
+                // `to_dynamic` panics for opaque fields, so fields which can't be cloned can't be reset
+                return field
+                    .reflect_clone()
+                    .ok()
+                    .map(|field| field.into_partial_reflect());
+            }
+        }
+
+        let type_id = value.get_represented_type_info()?.type_id();
+        self.get_default_value_for(type_id)
+            .map(|default| default.into_partial_reflect())
+    }
+
+    /// Right click menu on the label of a field, to copy its value as RON.
+    fn field_context_menu_readonly(
+        &mut self,
+        response: &egui::Response,
+        value: &dyn PartialReflect,
+    ) {
+        response
+            .interact(egui::Sense::click())
+            .context_menu(|ui| self.copy_button(ui, value));
+    }
+
+    fn copy_button(&mut self, ui: &mut egui::Ui, value: &dyn PartialReflect) {
+        let ron = to_ron(value, self.type_registry);
+        let response = ui.add_enabled(ron.is_ok(), egui::Button::new("Copy"));
+        let response = match &ron {
+            Ok(_) => response,
+            Err(error) => response.on_disabled_hover_text(error),
+        };
+        if let (true, Ok(ron), Some(info)) =
+            (response.clicked(), ron, value.get_represented_type_info())
+        {
+            ui.ctx().copy_text(ron.clone());
+            ui.data_mut(|data| {
+                data.insert_temp(
+                    CopiedValue::id(),
+                    CopiedValue {
+                        type_id: info.type_id(),
+                        ron,
+                    },
+                )
+            });
+            ui.close_menu();
+        }
     }
 }
 
@@ -1781,9 +2967,7 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
         InspectorUi {
             type_registry: self.type_registry,
             context: self.context,
-            short_circuit: self.short_circuit,
-            short_circuit_readonly: self.short_circuit_readonly,
-            short_circuit_many: self.short_circuit_many,
+            short_circuit: self.short_circuit.clone(),
         }
     }
 
@@ -1898,6 +3082,49 @@ fn maybe_grid_readonly_label_if(
     }
 }
 
+/// Splits the options of an enum into its [`EnumOptions`] and the options of its variant fields.
+///
+/// [`EnumOptions`] set on a field are combined with the ones set on the enum type.
+fn enum_options<'a>(
+    type_registry: &'a TypeRegistry,
+    type_id: TypeId,
+    options: &'a dyn Any,
+) -> (EnumOptions, &'a dyn Any) {
+    if let Some(field_enum_options) = options.downcast_ref::<EnumOptions>() {
+        let type_options = type_registry
+            .get_type_data::<ReflectInspectorOptions>(type_id)
+            .map(|data| &data.0);
+        let enum_options =
+            field_enum_options.merged_with(type_options.and_then(InspectorOptions::enum_options));
+        let options: &dyn Any = match type_options {
+            Some(type_options) => type_options,
+            None => &(),
+        };
+        (enum_options, options)
+    } else if let Some(inspector_options) = options.downcast_ref::<InspectorOptions>() {
+        let enum_options = inspector_options
+            .enum_options()
+            .cloned()
+            .unwrap_or_default();
+        (enum_options, options)
+    } else {
+        (EnumOptions::default(), options)
+    }
+}
+
+/// Radio buttons and segmented buttons are only used for enums with only unit variants
+fn enum_variant_display(info: &EnumInfo, enum_options: &EnumOptions) -> EnumDisplay {
+    let display = enum_options.display.unwrap_or_default();
+    let unit_only = info
+        .iter()
+        .all(|variant| matches!(variant, VariantInfo::Unit(_)));
+    if unit_only {
+        display
+    } else {
+        EnumDisplay::ComboBox
+    }
+}
+
 fn variant_constructable<'a>(
     type_registry: &TypeRegistry,
     variant: &'a VariantInfo,
@@ -1938,6 +3165,146 @@ fn inspector_options_struct_field(options: &dyn Any, field: usize) -> &dyn Any {
         .unwrap_or(&())
 }
 
+fn field_options(options: &dyn Any, target: Target) -> Option<&FieldOptions> {
+    options
+        .downcast_ref::<InspectorOptions>()?
+        .field_options(target)
+}
+
+/// The visible fields of a struct sorted by [`FieldOptions::order`], split into runs of ungrouped fields and [`FieldOptions::group`]s.
+///
+/// A group is positioned at its first field.
+fn struct_field_groups(
+    options: &dyn Any,
+    field_len: usize,
+    visible: impl Fn(usize) -> bool,
+) -> Vec<(Option<&str>, Vec<usize>)> {
+    let mut fields: Vec<usize> = (0..field_len).filter(|&i| visible(i)).collect();
+    fields.sort_by_key(|&i| field_options(options, Target::Field(i)).map_or(0, |o| o.order));
+
+    let mut groups: Vec<(Option<&str>, Vec<usize>)> = Vec::new();
+    for i in fields {
+        let group = field_options(options, Target::Field(i)).and_then(|o| o.group.as_deref());
+        let fields = match group {
+            None => match groups.last_mut() {
+                Some((None, fields)) => Some(fields),
+                _ => None,
+            },
+            Some(_) => groups
+                .iter_mut()
+                .find(|(other, _)| *other == group)
+                .map(|(_, fields)| fields),
+        };
+        match fields {
+            Some(fields) => fields.push(i),
+            None => groups.push((group, vec![i])),
+        }
+    }
+    groups
+}
+
+/// Whether a field of the struct or enum `parent` should be shown according to [`FieldOptions::hidden`] and [`FieldOptions::visible_if`]
+fn field_visible(field_options: Option<&FieldOptions>, parent: &dyn PartialReflect) -> bool {
+    let Some(field_options) = field_options else {
+        return true;
+    };
+    if field_options.hidden {
+        return false;
+    }
+    let Some(visible_if) = field_options.visible_if else {
+        return true;
+    };
+    parent
+        .try_as_reflect()
+        .is_none_or(|parent| visible_if(parent.as_any()))
+}
+
+/// Whether a field should be shown when editing multiple values at once, which is the case if it is visible for every value
+fn field_visible_many(
+    field_options: Option<&FieldOptions>,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> bool {
+    values
+        .iter_mut()
+        .all(|value| field_visible(field_options, projector(*value)))
+}
+
+/// Shows ungrouped fields in a grid and groups in a grid under a collapsing header.
+///
+/// If there are groups, the label column of every grid is as wide as the widest `label`, so that the values line up.
+fn show_field_groups<'a>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    groups: Vec<(Option<&str>, Vec<usize>)>,
+    label: impl Fn(usize) -> &'a str,
+    mut field_ui: impl FnMut(&mut egui::Ui, usize),
+) {
+    // `groups.iter()` would resolve to the `iter` of the reflection traits
+    let all_fields = groups.as_slice();
+    let label_width = all_fields
+        .iter()
+        .any(|(group, _)| group.is_some())
+        .then(|| {
+            all_fields
+                .iter()
+                .flat_map(|(_, fields)| fields)
+                .map(|&i| {
+                    WidgetText::from(label(i))
+                        .into_galley(
+                            ui,
+                            Some(egui::TextWrapMode::Extend),
+                            f32::INFINITY,
+                            egui::TextStyle::Body,
+                        )
+                        .size()
+                        .x
+                })
+                .fold(ui.spacing().interact_size.x, f32::max)
+        });
+    let indent = ui.spacing().indent;
+
+    for (n, (group, fields)) in groups.into_iter().enumerate() {
+        let grid_id = if n == 0 { id } else { id.with(("fields", n)) };
+        let mut grid = Grid::new(grid_id);
+        if let Some(label_width) = label_width {
+            // grids in groups are indented by the collapsing header
+            let indent = if group.is_some() { 0.0 } else { indent };
+            grid = grid.min_col_width(label_width + indent);
+        }
+        let show_grid = |ui: &mut egui::Ui| {
+            grid.show(ui, |ui| {
+                for i in fields {
+                    field_ui(ui, i);
+                    ui.end_row();
+                }
+            });
+        };
+        match group {
+            None => show_grid(ui),
+            Some(group) => {
+                egui::CollapsingHeader::new(group)
+                    .id_salt(id.with(group))
+                    .default_open(true)
+                    .show(ui, show_grid);
+            }
+        }
+    }
+}
+
+fn struct_field_label<'a>(options: &'a dyn Any, info: &'a StructInfo, field: usize) -> &'a str {
+    field_label(
+        field_options(options, Target::Field(field)),
+        info.field_at(field).unwrap().name(),
+    )
+}
+
+fn field_label<'a>(field_options: Option<&'a FieldOptions>, name: &'a str) -> &'a str {
+    field_options
+        .and_then(|field_options| field_options.label.as_deref())
+        .unwrap_or(name)
+}
+
 fn inspector_options_enum_variant_field<'a>(
     options: &'a dyn Any,
     variant_index: usize,
@@ -1954,6 +3321,90 @@ fn inspector_options_enum_variant_field<'a>(
         .unwrap_or(&())
 }
 
+/// The registration of `type_id` if it can be edited as RON, i.e. has [`ReflectSerialize`] and [`ReflectDeserialize`] type data.
+fn ron_registration(type_registry: &TypeRegistry, type_id: TypeId) -> Option<&TypeRegistration> {
+    let registration = type_registry.get(type_id)?;
+    (registration.data::<ReflectSerialize>().is_some()
+        && registration.data::<ReflectDeserialize>().is_some())
+    .then_some(registration)
+}
+
 fn or(a: bool, b: bool) -> bool {
     a || b
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bevy_platform::collections::HashMap;
+    use bevy_reflect::Map;
+
+    use super::{ShortCircuitHandler, ShortCircuitHandlers, check_list_len, common_map_keys};
+    use crate::inspector_options::std_options::ListOptions;
+
+    fn handler() -> ShortCircuitHandler {
+        ShortCircuitHandler::new(|_env, _value, _ui, _id, _options| None)
+    }
+
+    #[test]
+    fn handlers_are_ordered_by_descending_priority() {
+        let (first, second, third, fourth) = (handler(), handler(), handler(), handler());
+        let mut handlers = ShortCircuitHandlers::default();
+        handlers
+            .add(0, third.clone())
+            .add(10, first.clone())
+            .add(0, fourth.clone())
+            .add(5, second.clone());
+
+        let priorities: Vec<_> = handlers.handlers.iter().map(|(p, _)| *p).collect();
+        assert_eq!(priorities, [10, 5, 0, 0]);
+
+        // handlers with the same priority keep the order they were added in
+        let expected = [first, second, third, fourth];
+        for ((_, handler), expected) in handlers.handlers.iter().zip(expected) {
+            assert!(Arc::ptr_eq(
+                handler.short_circuit.as_ref().unwrap(),
+                expected.short_circuit.as_ref().unwrap()
+            ));
+        }
+    }
+
+    #[test]
+    fn list_len_is_checked_against_list_options() {
+        let options: ListOptions = ListOptions {
+            min_len: Some(2),
+            max_len: Some(3),
+            ..Default::default()
+        };
+        assert!(check_list_len(&options, &vec![1.0f32]).is_err());
+        assert!(check_list_len(&options, &vec![1.0f32, 2.0]).is_ok());
+        assert!(check_list_len(&options, &vec![1.0f32; 4]).is_err());
+
+        let options: ListOptions = ListOptions {
+            fixed_len: Some(3),
+            ..Default::default()
+        };
+        assert!(check_list_len(&options, &vec![1.0f32; 2]).is_err());
+        assert!(check_list_len(&options, &vec![1.0f32; 3]).is_ok());
+        assert!(check_list_len(&(), &vec![1.0f32]).is_ok());
+    }
+
+    #[test]
+    fn map_keys_missing_in_some_maps_are_reported() {
+        let first: HashMap<&str, i32> = [("a", 1), ("b", 2)].into();
+        let second: HashMap<&str, i32> = [("a", 3)].into();
+        let (keys, same_keys) = common_map_keys([&first as &dyn Map, &second]);
+        assert_eq!(keys.len(), 1);
+        assert!(!same_keys);
+
+        let (keys, same_keys) = common_map_keys([&second as &dyn Map, &first]);
+        assert_eq!(keys.len(), 1);
+        assert!(!same_keys);
+
+        let third: HashMap<&str, i32> = [("b", 4), ("a", 5)].into();
+        let (keys, same_keys) = common_map_keys([&first as &dyn Map, &third]);
+        assert_eq!(keys.len(), 2);
+        assert!(same_keys);
+    }
+}
@@ -1,3 +1,9 @@
+use bevy_reflect::{
+    PartialReflect, TypeRegistration, TypeRegistry,
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+};
+use serde::de::DeserializeSeed;
+
 pub fn pretty_type_name<T>() -> String {
     format!("{:?}", disqualified::ShortName::of::<T>())
 }
@@ -5,6 +11,31 @@ pub fn pretty_type_name_str(val: &str) -> String {
     format!("{:?}", disqualified::ShortName(val))
 }
 
+/// Serialize a value to RON without the surrounding type path
+pub(crate) fn to_ron(
+    value: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+) -> Result<String, String> {
+    let serializer = TypedReflectSerializer::new(value, type_registry);
+    ron::to_string(&serializer).map_err(|error| error.to_string())
+}
+
+/// Deserialize a value of the registered type from RON, as written by [`to_ron`]
+pub(crate) fn from_ron(
+    text: &str,
+    registration: &TypeRegistration,
+    type_registry: &TypeRegistry,
+) -> Result<Box<dyn PartialReflect>, String> {
+    let mut deserializer = ron::Deserializer::from_str(text).map_err(|error| error.to_string())?;
+    let value = TypedReflectDeserializer::new(registration, type_registry)
+        .deserialize(&mut deserializer)
+        .map_err(|error| deserializer.span_error(error).to_string())?;
+    deserializer
+        .end()
+        .map_err(|error| deserializer.span_error(error).to_string())?;
+    Ok(value)
+}
+
 pub mod guess_entity_name {
     use bevy_ecs::prelude::Name;
     use bevy_ecs::{archetype::Archetype, prelude::*, world::unsafe_world_cell::UnsafeWorldCell};